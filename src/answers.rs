@@ -0,0 +1,213 @@
+use std::{fmt::Write, fs, io, path::Path};
+
+use crate::{input, json::Value, ParseError, Part, Puzzle};
+
+/// Where the accepted answers are kept, relative to the repository root.
+pub const DEFAULT_PATH: &str = "answers.json";
+
+/// An accepted answer for one part of a specific input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: Part,
+    /// `input::key` of the input and parameters this answer belongs to.
+    pub fingerprint: String,
+    pub answer: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers(Vec<Answer>);
+
+impl Answers {
+    pub fn get(&self, day: u8, part: Part, fingerprint: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|a| a.day == day && a.part == part && a.fingerprint == fingerprint)
+            .map(|a| a.answer.as_str())
+    }
+
+    /// Accepts an answer, replacing the previous one for the same input.
+    pub fn insert(&mut self, answer: Answer) {
+        self.0.retain(|a| {
+            (a.day, a.part, &a.fingerprint) != (answer.day, answer.part, &answer.fingerprint)
+        });
+        self.0.push(answer);
+        self.0.sort_by_key(|a| (a.day, a.part));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Answer> {
+        self.0.iter()
+    }
+
+    pub fn to_json(&self) -> Value {
+        let answers = self
+            .0
+            .iter()
+            .map(|a| {
+                Value::Object(vec![
+                    ("day".to_owned(), u64::from(a.day).into()),
+                    ("part".to_owned(), u64::from(a.part.number()).into()),
+                    ("input".to_owned(), a.fingerprint.as_str().into()),
+                    ("answer".to_owned(), a.answer.as_str().into()),
+                ])
+            })
+            .collect();
+
+        Value::Object(vec![("answers".to_owned(), Value::Array(answers))])
+    }
+
+    pub fn from_json(value: &Value) -> Option<Self> {
+        value
+            .get("answers")?
+            .as_array()?
+            .iter()
+            .map(|a| {
+                Some(Answer {
+                    day: a.get("day")?.as_u64()? as u8,
+                    part: Part::from_number(a.get("part")?.as_u64()? as u8)?,
+                    fingerprint: a.get("input")?.as_str()?.to_owned(),
+                    answer: a.get("answer")?.as_str()?.to_owned(),
+                })
+            })
+            .collect::<Option<_>>()
+            .map(Answers)
+    }
+
+    /// Reads an answers file, treating a missing file as having no answers yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(error) => return Err(error),
+        };
+        let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+
+        let value = crate::json::parse(&contents).map_err(|error| invalid(error.to_string()))?;
+
+        Self::from_json(&value).ok_or_else(|| invalid("Not an answers file".to_owned()))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_json().pretty() + "\n")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// There's no accepted answer for this input yet.
+    Unknown,
+}
+
+/// The result of checking one part of a day against the accepted answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub fingerprint: String,
+    pub actual: String,
+    pub status: Status,
+}
+
+/// Solves both parts of a day and compares them to the accepted answers.
+pub fn verify(
+    answers: &Answers,
+    puzzle: &dyn Puzzle,
+    input: &str,
+) -> Result<Vec<Check>, ParseError> {
+    let fingerprint = input::key(puzzle, input);
+
+    let checks = puzzle
+        .solve(input, &Part::ALL)?
+        .into_iter()
+        .map(|(part, actual)| {
+            let status = match answers.get(puzzle.day(), part, &fingerprint) {
+                Some(expected) if expected == actual => Status::Pass,
+                Some(expected) => Status::Fail {
+                    expected: expected.to_owned(),
+                },
+                None => Status::Unknown,
+            };
+
+            Check {
+                day: puzzle.day(),
+                part,
+                fingerprint: fingerprint.clone(),
+                actual,
+                status,
+            }
+        })
+        .collect();
+
+    Ok(checks)
+}
+
+pub fn table(checks: &[Check]) -> String {
+    // Inputs solved with changed parameters have longer keys.
+    let width = checks
+        .iter()
+        .map(|check| check.fingerprint.len())
+        .fold(16, usize::max);
+    let mut result = format!(
+        "{:<4} {:<5} {:<width$} {:<7} {}",
+        "Day",
+        "Part",
+        "Input",
+        "Status",
+        "Answer",
+        width = width
+    );
+
+    for check in checks {
+        let (status, answer) = match &check.status {
+            Status::Pass => ("pass", check.actual.clone()),
+            Status::Fail { expected } => (
+                "FAIL",
+                format!("expected {}, got {}", expected, check.actual),
+            ),
+            Status::Unknown => ("unknown", format!("{} (not accepted yet)", check.actual)),
+        };
+
+        write!(
+            result,
+            "\n{:<4} {:<5} {:<width$} {:<7} {}",
+            check.day,
+            check.part.number(),
+            check.fingerprint,
+            status,
+            answer,
+            width = width
+        )
+        .unwrap();
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_replaces_answers_for_the_same_input() {
+        let answer = |fingerprint: &str, answer: &str| Answer {
+            day: 1,
+            part: Part::One,
+            fingerprint: fingerprint.to_owned(),
+            answer: answer.to_owned(),
+        };
+        let mut answers = Answers::default();
+
+        answers.insert(answer("aaaa", "1"));
+        answers.insert(answer("bbbb", "2"));
+        answers.insert(answer("aaaa", "3"));
+
+        assert_eq!(answers.get(1, Part::One, "aaaa"), Some("3"));
+        assert_eq!(answers.get(1, Part::One, "bbbb"), Some("2"));
+        assert_eq!(answers.get(1, Part::Two, "aaaa"), None);
+        assert_eq!(Answers::from_json(&answers.to_json()), Some(answers));
+    }
+}
@@ -0,0 +1,279 @@
+use std::{fmt::Write, fs, io, path::Path, time::Duration};
+
+use crate::{json::Value, ParseError, Part, Puzzle};
+
+pub const DEFAULT_ITERATIONS: usize = 100;
+
+/// How much slower (in percent) a median can get before it's flagged as a regression.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Parse,
+    Part(Part),
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::Part(Part::One), Phase::Part(Part::Two)];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part(Part::One) => "part1",
+            Phase::Part(Part::Two) => "part2",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|phase| phase.name() == name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Summarizes a non-empty set of samples, using nearest-rank percentiles.
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        samples.sort_unstable();
+
+        let percentile = |p: f64| {
+            let rank = (p * samples.len() as f64).ceil() as usize;
+
+            samples[rank.clamp(1, samples.len()) - 1]
+        };
+
+        Self {
+            min: samples[0],
+            median: percentile(0.5),
+            p95: percentile(0.95),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Measurement {
+    pub day: u8,
+    pub phase: Phase,
+    pub stats: Stats,
+}
+
+/// Solves a day `iterations` times (after one warm-up run) and summarizes each phase.
+pub fn run(
+    puzzle: &dyn Puzzle,
+    input: &str,
+    iterations: usize,
+) -> Result<Vec<Measurement>, ParseError> {
+    let iterations = iterations.max(1);
+    let mut samples = vec![Vec::with_capacity(iterations); Phase::ALL.len()];
+
+    puzzle.solve_timed(input, &Part::ALL)?;
+
+    for _ in 0..iterations {
+        let (_, timings) = puzzle.solve_timed(input, &Part::ALL)?;
+
+        samples[0].push(timings.parse);
+        for (i, (_, duration)) in timings.parts.iter().enumerate() {
+            samples[i + 1].push(*duration);
+        }
+    }
+
+    let measurements = Phase::ALL
+        .iter()
+        .zip(samples.iter_mut())
+        .map(|(phase, samples)| Measurement {
+            day: puzzle.day(),
+            phase: *phase,
+            stats: Stats::from_samples(samples),
+        })
+        .collect();
+
+    Ok(measurements)
+}
+
+pub fn to_json(measurements: &[Measurement]) -> Value {
+    let results = measurements
+        .iter()
+        .map(|measurement| {
+            let nanos = |duration: Duration| Value::from(duration.as_nanos() as u64);
+
+            Value::Object(vec![
+                ("day".to_owned(), u64::from(measurement.day).into()),
+                ("phase".to_owned(), measurement.phase.name().into()),
+                ("min_ns".to_owned(), nanos(measurement.stats.min)),
+                ("median_ns".to_owned(), nanos(measurement.stats.median)),
+                ("p95_ns".to_owned(), nanos(measurement.stats.p95)),
+            ])
+        })
+        .collect();
+
+    Value::Object(vec![("results".to_owned(), Value::Array(results))])
+}
+
+pub fn from_json(value: &Value) -> Option<Vec<Measurement>> {
+    value
+        .get("results")?
+        .as_array()?
+        .iter()
+        .map(|result| {
+            let nanos = |key| result.get(key)?.as_u64().map(Duration::from_nanos);
+
+            Some(Measurement {
+                day: result.get("day")?.as_u64()? as u8,
+                phase: Phase::from_name(result.get("phase")?.as_str()?)?,
+                stats: Stats {
+                    min: nanos("min_ns")?,
+                    median: nanos("median_ns")?,
+                    p95: nanos("p95_ns")?,
+                },
+            })
+        })
+        .collect()
+}
+
+pub fn save_baseline(path: &Path, measurements: &[Measurement]) -> io::Result<()> {
+    fs::write(path, to_json(measurements).pretty() + "\n")
+}
+
+pub fn load_baseline(path: &Path) -> io::Result<Vec<Measurement>> {
+    let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+
+    let value = crate::json::parse(&fs::read_to_string(path)?)
+        .map_err(|error| invalid(error.to_string()))?;
+
+    from_json(&value).ok_or_else(|| invalid("Not a benchmark baseline".to_owned()))
+}
+
+/// A measurement next to the same day and phase from a baseline, if there was one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Comparison {
+    pub measurement: Measurement,
+    pub baseline: Option<Stats>,
+    /// Change of the median against the baseline, in percent.
+    pub change: Option<f64>,
+    pub regression: bool,
+}
+
+pub fn compare(
+    measurements: &[Measurement],
+    baseline: &[Measurement],
+    threshold: f64,
+) -> Vec<Comparison> {
+    measurements
+        .iter()
+        .map(|measurement| {
+            let baseline = baseline
+                .iter()
+                .find(|old| old.day == measurement.day && old.phase == measurement.phase)
+                .map(|old| old.stats);
+
+            let change = baseline.map(|old| {
+                let old = old.median.as_secs_f64();
+                let new = measurement.stats.median.as_secs_f64();
+
+                if old == 0.0 {
+                    0.0
+                } else {
+                    (new - old) / old * 100.0
+                }
+            });
+
+            Comparison {
+                measurement: *measurement,
+                baseline,
+                change,
+                regression: change.is_some_and(|change| change > threshold),
+            }
+        })
+        .collect()
+}
+
+pub fn table(comparisons: &[Comparison]) -> String {
+    let with_baseline = comparisons.iter().any(|c| c.baseline.is_some());
+    let mut result = format!(
+        "{:<4} {:<6} {:>12} {:>12} {:>12}",
+        "Day", "Phase", "Min", "Median", "P95"
+    );
+
+    if with_baseline {
+        write!(result, " {:>12} {:>9}", "Baseline", "Change").unwrap();
+    }
+
+    for comparison in comparisons {
+        let Measurement { day, phase, stats } = comparison.measurement;
+
+        write!(
+            result,
+            "\n{:<4} {:<6} {:>12} {:>12} {:>12}",
+            day,
+            phase.name(),
+            format!("{:.1?}", stats.min),
+            format!("{:.1?}", stats.median),
+            format!("{:.1?}", stats.p95),
+        )
+        .unwrap();
+
+        if let (Some(baseline), Some(change)) = (comparison.baseline, comparison.change) {
+            write!(
+                result,
+                " {:>12} {:>+8.1}%",
+                format!("{:.1?}", baseline.median),
+                change
+            )
+            .unwrap();
+
+            if comparison.regression {
+                result.push_str("  REGRESSION");
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_use_nearest_rank() {
+        let mut samples = (1..=20)
+            .rev()
+            .map(Duration::from_millis)
+            .collect::<Vec<_>>();
+
+        let stats = Stats::from_samples(&mut samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(10));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+    }
+
+    #[test]
+    fn flags_slower_medians() {
+        let measurement = |day, median| Measurement {
+            day,
+            phase: Phase::Parse,
+            stats: Stats {
+                min: median,
+                median,
+                p95: median,
+            },
+        };
+        let baseline = [measurement(1, Duration::from_micros(100))];
+        let current = [
+            measurement(1, Duration::from_micros(125)),
+            measurement(2, Duration::from_micros(1)),
+        ];
+
+        let comparisons = compare(&current, &baseline, DEFAULT_THRESHOLD);
+
+        assert!(comparisons[0].regression);
+        assert_eq!(comparisons[1].baseline, None);
+        assert_eq!(from_json(&to_json(&current)), Some(current.to_vec()));
+    }
+}
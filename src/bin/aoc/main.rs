@@ -0,0 +1,538 @@
+use std::{env, path::PathBuf, process};
+
+use aoc_2021::{
+    answers::{self, Answer, Answers, Status},
+    bench,
+    config::{self, Config},
+    days,
+    generate::{self, Rng},
+    input::{self, Source},
+    output::{self, Format},
+    runner, Part, Puzzle,
+};
+
+const USAGE: &str = "\
+Usage:
+    aoc run (--day <day> | --all) [--part <part>] [--input <path>] [--format text|json|csv]
+    aoc bench (--day <day> | --all) [--input <path>] [--iterations <n>]
+              [--save <baseline.json>] [--baseline <baseline.json>] [--threshold <percent>]
+    aoc verify [--day <day> | --all] [--input <path>] [--answers <answers.json>] [--accept]
+    aoc params [--day <day> | --all]
+    aoc report --day <day> [--input <path>]
+    aoc generate --day <day> [--size <n>] [--seed <n>]
+
+Every command also accepts [--config <aoc.toml>] and, with a single --day, [--set <name>=<value>]...
+
+Inputs are read from inputs/day<day>.txt unless --input is given (- reads stdin).
+Puzzle parameters are read from aoc.toml unless --config is given, --set overrides them.";
+
+enum Command {
+    Run {
+        days: Vec<u8>,
+        parts: Vec<Part>,
+        source: Source,
+        format: Format,
+    },
+    Bench {
+        days: Vec<u8>,
+        source: Source,
+        iterations: usize,
+        save: Option<PathBuf>,
+        baseline: Option<PathBuf>,
+        threshold: f64,
+    },
+    Verify {
+        days: Vec<u8>,
+        source: Source,
+        answers: PathBuf,
+        accept: bool,
+    },
+    Params {
+        days: Vec<u8>,
+    },
+    Report {
+        day: u8,
+        source: Source,
+    },
+    Generate {
+        day: u8,
+        size: Option<usize>,
+        seed: Option<u64>,
+    },
+}
+
+/// Where puzzle parameters come from, for any command.
+struct ConfigArgs {
+    path: Option<PathBuf>,
+    /// `name=value` assignments from `--set`, along with the day they're for.
+    settings: Vec<(u8, String)>,
+}
+
+/// Every option understood by some command, before checking they make sense together.
+struct Options {
+    day: Option<u8>,
+    all: bool,
+    part: Option<Part>,
+    source: Source,
+    format: Option<Format>,
+    iterations: Option<usize>,
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: Option<f64>,
+    answers: Option<PathBuf>,
+    accept: bool,
+    config: Option<PathBuf>,
+    settings: Vec<String>,
+    size: Option<usize>,
+    seed: Option<u64>,
+}
+
+impl Options {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = Options {
+            day: None,
+            all: false,
+            part: None,
+            source: Source::Default,
+            format: None,
+            iterations: None,
+            save: None,
+            baseline: None,
+            threshold: None,
+            answers: None,
+            accept: false,
+            config: None,
+            settings: vec![],
+            size: None,
+            seed: None,
+        };
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("Missing value for {}", arg))
+            };
+
+            match arg.as_str() {
+                "--day" => {
+                    let value = value()?;
+                    let number = value
+                        .parse()
+                        .ok()
+                        .filter(|number| days::get(*number).is_some())
+                        .ok_or_else(|| format!("Invalid day: {}", value))?;
+
+                    options.day = Some(number);
+                }
+                "--part" => {
+                    let value = value()?;
+                    let number = value
+                        .parse()
+                        .ok()
+                        .and_then(Part::from_number)
+                        .ok_or_else(|| format!("Invalid part: {}", value))?;
+
+                    options.part = Some(number);
+                }
+                "--input" => options.source = Source::from_arg(value()?),
+                "--format" => {
+                    let value = value()?;
+                    let format = Format::from_name(value)
+                        .ok_or_else(|| format!("Invalid format: {}", value))?;
+
+                    options.format = Some(format);
+                }
+                "--iterations" => {
+                    let value = value()?;
+                    let iterations = value
+                        .parse()
+                        .ok()
+                        .filter(|iterations| *iterations > 0)
+                        .ok_or_else(|| format!("Invalid number of iterations: {}", value))?;
+
+                    options.iterations = Some(iterations);
+                }
+                "--save" => options.save = Some(PathBuf::from(value()?)),
+                "--baseline" => options.baseline = Some(PathBuf::from(value()?)),
+                "--threshold" => {
+                    let value = value()?;
+                    let threshold = value
+                        .parse()
+                        .ok()
+                        .filter(|threshold: &f64| *threshold >= 0.0)
+                        .ok_or_else(|| format!("Invalid threshold: {}", value))?;
+
+                    options.threshold = Some(threshold);
+                }
+                "--answers" => options.answers = Some(PathBuf::from(value()?)),
+                "--accept" => options.accept = true,
+                "--config" => options.config = Some(PathBuf::from(value()?)),
+                "--set" => options.settings.push(value()?.clone()),
+                "--size" => {
+                    let value = value()?;
+                    let size = value
+                        .parse()
+                        .ok()
+                        .filter(|size| *size > 0)
+                        .ok_or_else(|| format!("Invalid size: {}", value))?;
+
+                    options.size = Some(size);
+                }
+                "--seed" => {
+                    let value = value()?;
+                    let seed = value
+                        .parse()
+                        .map_err(|_| format!("Invalid seed: {}", value))?;
+
+                    options.seed = Some(seed);
+                }
+                "--all" => options.all = true,
+                _ => return Err(format!("Unknown option: {}", arg)),
+            }
+        }
+
+        Ok(options)
+    }
+
+    fn days(&self) -> Result<Vec<u8>, String> {
+        if self.all && self.source != Source::Default {
+            return Err("--input can only be used with a single --day".to_owned());
+        }
+
+        match (self.day, self.all) {
+            (Some(day), false) => Ok(vec![day]),
+            (None, true) => Ok((1..=days::LAST_DAY).collect()),
+            (Some(_), true) => Err("--day and --all can't be used together".to_owned()),
+            (None, false) => Err("Expected either --day or --all".to_owned()),
+        }
+    }
+
+    /// Fails if any option outside of `allowed` was given.
+    fn only(&self, command: &str, allowed: &[&str]) -> Result<(), String> {
+        let given = [
+            ("--part", self.part.is_some()),
+            ("--format", self.format.is_some()),
+            ("--iterations", self.iterations.is_some()),
+            ("--save", self.save.is_some()),
+            ("--baseline", self.baseline.is_some()),
+            ("--threshold", self.threshold.is_some()),
+            ("--answers", self.answers.is_some()),
+            ("--accept", self.accept),
+            ("--size", self.size.is_some()),
+            ("--seed", self.seed.is_some()),
+        ];
+
+        match given
+            .iter()
+            .find(|(name, given)| *given && !allowed.contains(name))
+        {
+            Some((name, _)) => Err(format!("{} can't be used with {}", name, command)),
+            None => Ok(()),
+        }
+    }
+
+    fn config(&self) -> Result<ConfigArgs, String> {
+        let settings = match (self.day, self.all) {
+            (Some(day), false) => self.settings.iter().map(|s| (day, s.clone())).collect(),
+            _ if self.settings.is_empty() => vec![],
+            _ => return Err("--set can only be used with a single --day".to_owned()),
+        };
+
+        Ok(ConfigArgs {
+            path: self.config.clone(),
+            settings,
+        })
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<(Command, ConfigArgs), String> {
+    let (command, options) = args.split_first().ok_or("Missing command")?;
+    let mut options = Options::parse(options)?;
+
+    // Unlike the other commands, verify and params cover every day by default.
+    if options.day.is_none() && ["verify", "params"].contains(&command.as_str()) {
+        options.all = true;
+    }
+
+    let config = options.config()?;
+    let command = match command.as_str() {
+        "run" => {
+            options.only("run", &["--part", "--format"])?;
+
+            let parts = match options.part {
+                Some(part) => vec![part],
+                None => Part::ALL.to_vec(),
+            };
+
+            Ok(Command::Run {
+                days: options.days()?,
+                parts,
+                source: options.source,
+                format: options.format.unwrap_or(Format::Text),
+            })
+        }
+        "bench" => {
+            options.only(
+                "bench",
+                &["--iterations", "--save", "--baseline", "--threshold"],
+            )?;
+
+            Ok(Command::Bench {
+                days: options.days()?,
+                source: options.source,
+                iterations: options.iterations.unwrap_or(bench::DEFAULT_ITERATIONS),
+                save: options.save,
+                baseline: options.baseline,
+                threshold: options.threshold.unwrap_or(bench::DEFAULT_THRESHOLD),
+            })
+        }
+        "verify" => {
+            options.only("verify", &["--answers", "--accept"])?;
+
+            Ok(Command::Verify {
+                days: options.days()?,
+                source: options.source,
+                answers: options
+                    .answers
+                    .unwrap_or_else(|| PathBuf::from(answers::DEFAULT_PATH)),
+                accept: options.accept,
+            })
+        }
+        "params" => {
+            options.only("params", &[])?;
+
+            if options.source != Source::Default {
+                return Err("--input can't be used with params".to_owned());
+            }
+
+            Ok(Command::Params {
+                days: options.days()?,
+            })
+        }
+        "report" => {
+            options.only("report", &[])?;
+
+            match options.days()?.as_slice() {
+                [day] => Ok(Command::Report {
+                    day: *day,
+                    source: options.source,
+                }),
+                _ => Err("report works on a single --day".to_owned()),
+            }
+        }
+        "generate" => {
+            options.only("generate", &["--size", "--seed"])?;
+
+            match (options.days()?.as_slice(), &options.source) {
+                ([day], Source::Default) => Ok(Command::Generate {
+                    day: *day,
+                    size: options.size,
+                    seed: options.seed,
+                }),
+                (_, Source::Default) => Err("generate works on a single --day".to_owned()),
+                _ => Err("--input can't be used with generate".to_owned()),
+            }
+        }
+        _ => Err(format!("Unknown command: {}", command)),
+    }?;
+
+    Ok((command, config))
+}
+
+/// The puzzle for a day, with its parameters set from the config.
+fn puzzle(day: u8, config: &Config) -> Box<dyn Puzzle> {
+    let mut puzzle = days::get(day).unwrap();
+    runner::configure(puzzle.as_mut(), config);
+
+    puzzle
+}
+
+fn load_input(day: u8, source: &Source) -> String {
+    input::load(day, source).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(1);
+    })
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    let (command, config_args) = parse_args(&args).unwrap_or_else(|error| {
+        eprintln!("{}\n\n{}", error, USAGE);
+        process::exit(2);
+    });
+    let config = runner::load_config(config_args.path, &config_args.settings);
+
+    match command {
+        Command::Run {
+            days,
+            parts,
+            source,
+            format,
+        } => {
+            let mut records = vec![];
+            let mut failed = false;
+
+            for day in days {
+                let puzzle = puzzle(day, &config);
+                let input = load_input(day, &source);
+
+                match output::records(puzzle.as_ref(), &input, &parts) {
+                    Ok(day_records) => records.extend(day_records),
+                    Err(error) => {
+                        eprintln!("{}", runner::render_error(&error, &input));
+                        failed = true;
+                    }
+                }
+            }
+
+            println!("{}", format.render(&records, true));
+
+            if failed {
+                process::exit(1);
+            }
+        }
+        Command::Bench {
+            days,
+            source,
+            iterations,
+            save,
+            baseline,
+            threshold,
+        } => {
+            let baseline = baseline.map(|path| {
+                bench::load_baseline(&path).unwrap_or_else(|error| {
+                    eprintln!("Could not load baseline {}: {}", path.display(), error);
+                    process::exit(1);
+                })
+            });
+
+            let mut measurements = vec![];
+            for day in days {
+                let puzzle = puzzle(day, &config);
+                let input = load_input(day, &source);
+
+                match bench::run(puzzle.as_ref(), &input, iterations) {
+                    Ok(day_measurements) => measurements.extend(day_measurements),
+                    Err(error) => {
+                        eprintln!("{}", runner::render_error(&error, &input));
+                        process::exit(1);
+                    }
+                }
+            }
+
+            let comparisons =
+                bench::compare(&measurements, baseline.as_deref().unwrap_or(&[]), threshold);
+            println!("{}", bench::table(&comparisons));
+
+            if let Some(path) = save {
+                if let Err(error) = bench::save_baseline(&path, &measurements) {
+                    eprintln!("Could not save baseline {}: {}", path.display(), error);
+                    process::exit(1);
+                }
+            }
+
+            let regressions = comparisons.iter().filter(|c| c.regression).count();
+            if regressions > 0 {
+                eprintln!(
+                    "{} phase(s) got more than {}% slower than the baseline",
+                    regressions, threshold
+                );
+                process::exit(1);
+            }
+        }
+        Command::Verify {
+            days,
+            source,
+            answers: path,
+            accept,
+        } => {
+            let mut answers = Answers::load(&path).unwrap_or_else(|error| {
+                eprintln!("Could not load answers {}: {}", path.display(), error);
+                process::exit(1);
+            });
+
+            let mut checks = vec![];
+            let mut failed = false;
+            for day in days {
+                let puzzle = puzzle(day, &config);
+                let input = load_input(day, &source);
+
+                match answers::verify(&answers, puzzle.as_ref(), &input) {
+                    Ok(day_checks) => checks.extend(day_checks),
+                    Err(error) => {
+                        eprintln!("{}", runner::render_error(&error, &input));
+                        failed = true;
+                    }
+                }
+            }
+
+            println!("{}", answers::table(&checks));
+
+            if accept {
+                for check in &checks {
+                    answers.insert(Answer {
+                        day: check.day,
+                        part: check.part,
+                        fingerprint: check.fingerprint.clone(),
+                        answer: check.actual.clone(),
+                    });
+                }
+
+                if let Err(error) = answers.save(&path) {
+                    eprintln!("Could not save answers {}: {}", path.display(), error);
+                    process::exit(1);
+                }
+            } else if checks.iter().any(|check| check.status != Status::Pass) {
+                failed = true;
+            }
+
+            if failed {
+                process::exit(1);
+            }
+        }
+        Command::Params { days } => {
+            let params = days
+                .into_iter()
+                .flat_map(|day| {
+                    let puzzle = puzzle(day, &config);
+
+                    puzzle.params().into_iter().map(move |param| (day, param))
+                })
+                .collect::<Vec<_>>();
+
+            println!("{}", config::table(&params));
+        }
+        Command::Report { day, source } => {
+            let puzzle = puzzle(day, &config);
+            let input = load_input(day, &source);
+
+            match runner::print_report(puzzle.as_ref(), &input) {
+                Ok(true) => {}
+                Ok(false) => {
+                    eprintln!("Day {} has no report", day);
+                    process::exit(1);
+                }
+                Err(error) => {
+                    eprintln!("{}", runner::render_error(&error, &input));
+                    process::exit(1);
+                }
+            }
+        }
+        Command::Generate { day, size, seed } => {
+            let mut rng = match seed {
+                Some(seed) => Rng::new(seed),
+                None => {
+                    let seed = Rng::from_time().next_u64();
+                    eprintln!("Seed: {}", seed);
+
+                    Rng::new(seed)
+                }
+            };
+            let size = size.or_else(|| generate::default_size(day)).unwrap();
+
+            println!("{}", generate::generate(day, size, &mut rng).unwrap());
+        }
+    }
+}
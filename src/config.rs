@@ -0,0 +1,322 @@
+//! Puzzle parameters, and the `aoc.toml` file used to change them.
+//!
+//! The file understands a small subset of TOML: one `[day<N>]` table per day, holding
+//! `name = value` pairs. For example:
+//!
+//! ```toml
+//! [day6]
+//! part1_days = 18
+//! ```
+
+use std::{error::Error, fmt, fmt::Write, fs, io, path::Path, str::FromStr};
+
+use crate::Puzzle;
+
+/// Where the runners look for parameters unless told otherwise.
+pub const DEFAULT_PATH: &str = "aoc.toml";
+
+/// A puzzle constant that can be changed, along with its current value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub value: String,
+    pub description: &'static str,
+}
+
+impl Param {
+    pub fn new<T: ToString>(name: &'static str, value: T, description: &'static str) -> Self {
+        Self {
+            name,
+            value: value.to_string(),
+            description,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamError {
+    Unknown {
+        name: String,
+    },
+    Invalid {
+        name: String,
+        value: String,
+        expected: &'static str,
+    },
+}
+
+impl ParamError {
+    pub fn unknown(name: &str) -> Self {
+        ParamError::Unknown {
+            name: name.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParamError::Unknown { name } => write!(f, "unknown parameter `{}`", name),
+            ParamError::Invalid {
+                name,
+                value,
+                expected,
+            } => write!(
+                f,
+                "invalid value {:?} for `{}`, expected {}",
+                value, name, expected
+            ),
+        }
+    }
+}
+
+impl Error for ParamError {}
+
+/// Parses a parameter's value, accepting only values for which `valid` holds.
+pub fn parse_value<T, F>(
+    name: &str,
+    value: &str,
+    expected: &'static str,
+    valid: F,
+) -> Result<T, ParamError>
+where
+    T: FromStr,
+    F: FnOnce(&T) -> bool,
+{
+    value
+        .parse()
+        .ok()
+        .filter(valid)
+        .ok_or_else(|| ParamError::Invalid {
+            name: name.to_owned(),
+            value: value.to_owned(),
+            expected,
+        })
+}
+
+/// Where a setting came from, for error messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Origin {
+    /// A line of the config file.
+    Line(usize),
+    CommandLine,
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Origin::Line(line) => write!(f, "line {}", line),
+            Origin::CommandLine => write!(f, "command line"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Setting {
+    pub day: u8,
+    pub name: String,
+    pub value: String,
+    pub origin: Origin,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    Syntax {
+        line: usize,
+        message: &'static str,
+    },
+    Param {
+        day: u8,
+        origin: Origin,
+        error: ParamError,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Syntax { line, message } => write!(f, "line {}: {}", line, message),
+            ConfigError::Param { day, origin, error } => {
+                write!(f, "{}: day {}: {}", origin, day, error)
+            }
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+/// Parameter values to apply to puzzles, later settings taking precedence.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    settings: Vec<Setting>,
+}
+
+impl Config {
+    pub fn parse(text: &str) -> Result<Self, ConfigError> {
+        let mut config = Config::default();
+        let mut day = None;
+
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let syntax = |message| ConfigError::Syntax {
+                line: line_number,
+                message,
+            };
+            let line = strip_comment(line).trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(table) = line.strip_prefix('[') {
+                let number = table
+                    .strip_suffix(']')
+                    .and_then(|table| table.trim().strip_prefix("day"))
+                    .and_then(|number| number.parse().ok())
+                    .ok_or_else(|| syntax("expected a table like `[day6]`"))?;
+
+                day = Some(number);
+                continue;
+            }
+
+            let (name, value) = line
+                .split_once('=')
+                .ok_or_else(|| syntax("expected `name = value`"))?;
+            let day = day.ok_or_else(|| syntax("settings must be inside a `[day<N>]` table"))?;
+            let value = value.trim();
+            let value = match value.strip_prefix('"') {
+                Some(quoted) => quoted
+                    .strip_suffix('"')
+                    .ok_or_else(|| syntax("unterminated string"))?,
+                None => value,
+            };
+
+            config.settings.push(Setting {
+                day,
+                name: name.trim().to_owned(),
+                value: value.to_owned(),
+                origin: Origin::Line(line_number),
+            });
+        }
+
+        Ok(config)
+    }
+
+    /// Reads a config file, treating a missing file as an empty one.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(error) => return Err(error),
+        };
+
+        Self::parse(&text).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    /// Adds a `name=value` assignment given on the command line.
+    pub fn set_arg(&mut self, day: u8, assignment: &str) -> Option<()> {
+        let (name, value) = assignment.split_once('=')?;
+
+        self.settings.push(Setting {
+            day,
+            name: name.trim().to_owned(),
+            value: value.trim().to_owned(),
+            origin: Origin::CommandLine,
+        });
+
+        Some(())
+    }
+
+    pub fn settings(&self) -> &[Setting] {
+        &self.settings
+    }
+
+    /// Sets every parameter configured for the puzzle's day.
+    pub fn apply(&self, puzzle: &mut dyn Puzzle) -> Result<(), ConfigError> {
+        let day = puzzle.day();
+
+        for setting in self.settings.iter().filter(|s| s.day == day) {
+            puzzle
+                .set_param(&setting.name, &setting.value)
+                .map_err(|error| ConfigError::Param {
+                    day: setting.day,
+                    origin: setting.origin,
+                    error,
+                })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Lists the parameters of each day, as given by `Puzzle::params`.
+pub fn table(params: &[(u8, Param)]) -> String {
+    let mut result = format!(
+        "{:<4} {:<18} {:>6}  {}",
+        "Day", "Parameter", "Value", "Description"
+    );
+
+    for (day, param) in params {
+        write!(
+            result,
+            "\n{:<4} {:<18} {:>6}  {}",
+            day, param.name, param.value, param.description
+        )
+        .unwrap();
+    }
+
+    result
+}
+
+/// Drops a trailing `# comment`, unless the `#` is inside a string.
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+
+    for (index, c) in line.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..index],
+            _ => {}
+        }
+    }
+
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_day_tables() {
+        let config = Config::parse(
+            "# what if\n[day6]\npart1_days = 18 # fewer days\n\n[ day11 ]\nsteps = \"10\"\n",
+        )
+        .unwrap();
+
+        let settings = config
+            .settings()
+            .iter()
+            .map(|s| (s.day, s.name.as_str(), s.value.as_str(), s.origin))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            settings,
+            [
+                (6, "part1_days", "18", Origin::Line(3)),
+                (11, "steps", "10", Origin::Line(6)),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_settings_outside_tables() {
+        assert_eq!(
+            Config::parse("steps = 10"),
+            Err(ConfigError::Syntax {
+                line: 1,
+                message: "settings must be inside a `[day<N>]` table"
+            })
+        );
+    }
+}
@@ -0,0 +1,743 @@
+use std::{
+    collections::VecDeque,
+    error::Error,
+    fmt,
+    io::{self, BufRead},
+    iter,
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+use crate::{
+    config::{self, Param, ParamError},
+    error::{Invalid, ParseError, ParseErrorKind},
+    Solution,
+};
+
+/// A type of depth reading, like `usize`, `i64` or `f64`.
+///
+/// Sums of whole windows need to fit in it.
+pub trait Depth: Copy + PartialOrd + FromStr + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+
+    /// Multiplies the reading by a number of readings.
+    fn scale(self, count: usize) -> Self;
+
+    /// The reading `step` out of `steps` of the way from `from` to `to`.
+    fn lerp(from: Self, to: Self, step: usize, steps: usize) -> Self;
+
+    /// Converts a value that isn't negative, rounding it down for integers.
+    fn from_f64(value: f64) -> Self;
+
+    /// Whether a parsed reading makes sense, e.g. is not NaN.
+    fn is_valid(self) -> bool {
+        true
+    }
+}
+
+macro_rules! integer_depth {
+    ($($t:ty),*) => {$(
+        impl Depth for $t {
+            const ZERO: Self = 0;
+
+            fn scale(self, count: usize) -> Self {
+                self * count as $t
+            }
+
+            fn lerp(from: Self, to: Self, step: usize, steps: usize) -> Self {
+                let (from, to) = (from as f64, to as f64);
+
+                (from + (to - from) * step as f64 / steps as f64).round() as $t
+            }
+
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+        }
+    )*};
+}
+
+macro_rules! float_depth {
+    ($($t:ty),*) => {$(
+        impl Depth for $t {
+            const ZERO: Self = 0.0;
+
+            fn scale(self, count: usize) -> Self {
+                self * count as $t
+            }
+
+            fn lerp(from: Self, to: Self, step: usize, steps: usize) -> Self {
+                from + (to - from) * step as $t / steps as $t
+            }
+
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+
+            fn is_valid(self) -> bool {
+                self.is_finite()
+            }
+        }
+    )*};
+}
+
+integer_depth!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+float_depth!(f32, f64);
+
+/// How the measurements of a window are combined into a single value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    Sum,
+    Min,
+    Max,
+    Mean,
+}
+
+/// How consecutive windows compare to each other.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Trend {
+    pub increases: usize,
+    pub decreases: usize,
+    pub plateaus: usize,
+    /// Most increases in a row.
+    pub longest_increasing_run: usize,
+}
+
+/// The last `size` measurements and their aggregate, updated in amortized O(1) per push.
+#[derive(Debug, Clone)]
+struct Window<T> {
+    size: usize,
+    aggregate: Aggregate,
+    values: VecDeque<T>,
+    sum: T,
+    /// Values that can still become the min (or max) of the window, from oldest to newest.
+    ///
+    /// Each one is kept along with how many values were pushed before it.
+    extremes: VecDeque<(usize, T)>,
+    pushed: usize,
+}
+
+impl<T: Depth> Window<T> {
+    fn new(size: usize, aggregate: Aggregate) -> Self {
+        assert!(size > 0, "Windows need at least one measurement");
+
+        Self {
+            size,
+            aggregate,
+            values: VecDeque::with_capacity(size + 1),
+            sum: T::ZERO,
+            extremes: VecDeque::new(),
+            pushed: 0,
+        }
+    }
+
+    /// Adds a measurement, returning the window's aggregate once it's full.
+    fn push(&mut self, value: T) -> Option<T> {
+        self.values.push_back(value);
+        self.sum = self.sum + value;
+        if self.values.len() > self.size {
+            self.sum = self.sum - self.values.pop_front().unwrap();
+        }
+
+        // Older values that are no better than the new one can never be the extreme again.
+        let dominated: Option<fn(T, T) -> bool> = match self.aggregate {
+            Aggregate::Min => Some(|old, new| old >= new),
+            Aggregate::Max => Some(|old, new| old <= new),
+            Aggregate::Sum | Aggregate::Mean => None,
+        };
+        if let Some(dominated) = dominated {
+            while self
+                .extremes
+                .back()
+                .is_some_and(|(_, old)| dominated(*old, value))
+            {
+                self.extremes.pop_back();
+            }
+            self.extremes.push_back((self.pushed, value));
+            if self.pushed - self.extremes[0].0 >= self.size {
+                self.extremes.pop_front();
+            }
+        }
+        self.pushed += 1;
+
+        if self.values.len() < self.size {
+            return None;
+        }
+
+        let value = match self.aggregate {
+            // Every window has the same size, so means compare like sums do. Comparing sums
+            // (with a scaled tolerance) avoids rounding integer means.
+            Aggregate::Sum | Aggregate::Mean => self.sum,
+            Aggregate::Min | Aggregate::Max => self.extremes[0].1,
+        };
+
+        Some(value)
+    }
+}
+
+/// Compares each window of measurements with the previous one, one measurement at a time.
+#[derive(Debug, Clone)]
+pub struct Analyzer<T> {
+    window: Window<T>,
+    /// Changes no bigger than this count as plateaus.
+    tolerance: T,
+    previous: Option<T>,
+    increasing_run: usize,
+    trend: Trend,
+    measurements: usize,
+}
+
+impl<T: Depth> Analyzer<T> {
+    pub fn new(window_size: usize, aggregate: Aggregate) -> Self {
+        Self {
+            window: Window::new(window_size, aggregate),
+            tolerance: T::ZERO,
+            previous: None,
+            increasing_run: 0,
+            trend: Trend::default(),
+            measurements: 0,
+        }
+    }
+
+    /// Only counts windows as increases or decreases when they change by more than `tolerance`.
+    pub fn with_tolerance(mut self, tolerance: T) -> Self {
+        self.tolerance = match self.window.aggregate {
+            Aggregate::Mean => tolerance.scale(self.window.size),
+            Aggregate::Sum | Aggregate::Min | Aggregate::Max => tolerance,
+        };
+
+        self
+    }
+
+    pub fn push(&mut self, measurement: T) {
+        self.measurements += 1;
+
+        let current = match self.window.push(measurement) {
+            Some(current) => current,
+            None => return,
+        };
+
+        if let Some(previous) = self.previous {
+            let tolerance = self.tolerance;
+
+            // Checking the order first keeps unsigned readings from underflowing.
+            if current > previous && current - previous > tolerance {
+                self.trend.increases += 1;
+                self.increasing_run += 1;
+                self.trend.longest_increasing_run =
+                    self.trend.longest_increasing_run.max(self.increasing_run);
+            } else if current < previous && previous - current > tolerance {
+                self.trend.decreases += 1;
+                self.increasing_run = 0;
+            } else {
+                self.trend.plateaus += 1;
+                self.increasing_run = 0;
+            }
+        }
+
+        self.previous = Some(current);
+    }
+
+    pub fn trend(&self) -> Trend {
+        self.trend
+    }
+
+    /// How many measurements were pushed so far.
+    pub fn measurements(&self) -> usize {
+        self.measurements
+    }
+}
+
+pub fn analyze<T: Depth>(
+    measurements: &[T],
+    window_size: usize,
+    aggregate: Aggregate,
+    tolerance: T,
+) -> Trend {
+    let mut analyzer = Analyzer::new(window_size, aggregate).with_tolerance(tolerance);
+
+    for measurement in measurements {
+        analyzer.push(*measurement);
+    }
+
+    analyzer.trend()
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Io(error) => write!(f, "Could not read measurements: {}", error),
+            StreamError::Parse(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(error: io::Error) -> Self {
+        StreamError::Io(error)
+    }
+}
+
+/// What to do with lines that don't hold a valid reading, like blank ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BadLines {
+    Fail,
+    Skip,
+    /// Replaces them with readings evenly spaced between the good ones around them.
+    ///
+    /// Bad lines before the first or after the last good reading are skipped.
+    Interpolate,
+}
+
+impl fmt::Display for BadLines {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            BadLines::Fail => "fail",
+            BadLines::Skip => "skip",
+            BadLines::Interpolate => "interpolate",
+        };
+
+        f.pad(name)
+    }
+}
+
+impl FromStr for BadLines {
+    type Err = ();
+
+    fn from_str(name: &str) -> Result<Self, ()> {
+        match name {
+            "fail" => Ok(BadLines::Fail),
+            "skip" => Ok(BadLines::Skip),
+            "interpolate" => Ok(BadLines::Interpolate),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Turns lines into readings, following a `BadLines` policy.
+struct Readings<T> {
+    bad_lines: BadLines,
+    last: Option<T>,
+    /// Bad lines since the last good reading.
+    pending: usize,
+}
+
+impl<T: Depth> Readings<T> {
+    fn new(bad_lines: BadLines) -> Self {
+        Self {
+            bad_lines,
+            last: None,
+            pending: 0,
+        }
+    }
+
+    /// Reads a line, passing on the readings it results in to `emit`.
+    fn read<'a, F: FnMut(T)>(&mut self, line: &'a str, emit: F) -> Result<(), Invalid<'a>> {
+        let text = line.trim();
+        let reading = match text.parse::<T>() {
+            Ok(reading) if reading.is_valid() => reading,
+            _ if self.bad_lines != BadLines::Fail => {
+                self.pending += 1;
+                return Ok(());
+            }
+            _ if text.is_empty() => return Err(Invalid::new(line, ParseErrorKind::Empty)),
+            _ => return Err(Invalid::new(text, ParseErrorKind::InvalidNumber)),
+        };
+
+        let interpolated = match (self.bad_lines, self.last) {
+            (BadLines::Interpolate, Some(_)) => self.pending,
+            _ => 0,
+        };
+        let last = self.last.unwrap_or(reading);
+        (1..=interpolated)
+            .map(|step| T::lerp(last, reading, step, interpolated + 1))
+            .chain(iter::once(reading))
+            .for_each(emit);
+
+        self.last = Some(reading);
+        self.pending = 0;
+
+        Ok(())
+    }
+}
+
+/// Parses one reading per line.
+pub fn parse_measurements<T: Depth>(
+    input: &str,
+    bad_lines: BadLines,
+) -> Result<Vec<T>, ParseError> {
+    let mut readings = Readings::new(bad_lines);
+    let mut measurements = vec![];
+
+    for line in input.lines() {
+        readings
+            .read(line, |measurement| measurements.push(measurement))
+            .map_err(|invalid| ParseError::locate(Day1::DAY, input, invalid))?;
+    }
+
+    Ok(measurements)
+}
+
+/// Feeds measurements to `analyzer` as they're read, one line at a time.
+///
+/// Only the current line and the analyzer's window are kept in memory, so `reader` can be
+/// arbitrarily long. When `report_every` is given, `report` is called with the analyzer
+/// after that many measurements, and then again after every as many more. It can't be 0.
+pub fn stream<T, R, F>(
+    mut reader: R,
+    analyzer: &mut Analyzer<T>,
+    bad_lines: BadLines,
+    report_every: Option<usize>,
+    mut report: F,
+) -> Result<Trend, StreamError>
+where
+    T: Depth,
+    R: BufRead,
+    F: FnMut(&Analyzer<T>),
+{
+    assert!(report_every != Some(0), "Can't report every 0 measurements");
+
+    let mut readings = Readings::new(bad_lines);
+    let mut line = String::new();
+    let mut line_number = 0;
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(analyzer.trend());
+        }
+        line_number += 1;
+
+        let text = line.trim_end_matches(&['\n', '\r'][..]);
+        readings
+            .read(text, |measurement| {
+                analyzer.push(measurement);
+
+                if let Some(every) = report_every {
+                    // `is_multiple_of` needs a much newer compiler than the rest of the crate.
+                    #[allow(clippy::manual_is_multiple_of)]
+                    if analyzer.measurements() % every == 0 {
+                        report(analyzer);
+                    }
+                }
+            })
+            .map_err(|invalid| {
+                let error = ParseError::locate(Day1::DAY, text, invalid);
+
+                StreamError::Parse(ParseError {
+                    line: line_number,
+                    ..error
+                })
+            })?;
+    }
+}
+
+pub fn part1<T: Depth>(measurements: &[T], tolerance: T) -> usize {
+    analyze(measurements, 1, Aggregate::Sum, tolerance).increases
+}
+
+pub fn part2<T: Depth>(measurements: &[T], tolerance: T) -> usize {
+    analyze(measurements, 3, Aggregate::Sum, tolerance).increases
+}
+
+/// The types `Day1` can read measurements as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reading {
+    /// `usize`, like the puzzle's depths.
+    Unsigned,
+    /// `i64`, for readings above the surface.
+    Signed,
+    /// `f64`.
+    Decimal,
+}
+
+impl fmt::Display for Reading {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Reading::Unsigned => "unsigned",
+            Reading::Signed => "signed",
+            Reading::Decimal => "decimal",
+        };
+
+        f.pad(name)
+    }
+}
+
+impl FromStr for Reading {
+    type Err = ();
+
+    fn from_str(name: &str) -> Result<Self, ()> {
+        match name {
+            "unsigned" => Ok(Reading::Unsigned),
+            "signed" => Ok(Reading::Signed),
+            "decimal" => Ok(Reading::Decimal),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Measurements read as one of the `Reading` types.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Measurements {
+    Unsigned(Vec<usize>),
+    Signed(Vec<i64>),
+    Decimal(Vec<f64>),
+}
+
+pub struct Day1 {
+    pub readings: Reading,
+    pub bad_lines: BadLines,
+    /// Windows changing by at most this much are neither increases nor decreases.
+    pub tolerance: f64,
+}
+
+impl Default for Day1 {
+    fn default() -> Self {
+        Self {
+            readings: Reading::Unsigned,
+            bad_lines: BadLines::Fail,
+            tolerance: 0.0,
+        }
+    }
+}
+
+impl Solution for Day1 {
+    type Input = Measurements;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const DAY: u8 = 1;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let measurements = match self.readings {
+            Reading::Unsigned => Measurements::Unsigned(parse_measurements(input, self.bad_lines)?),
+            Reading::Signed => Measurements::Signed(parse_measurements(input, self.bad_lines)?),
+            Reading::Decimal => Measurements::Decimal(parse_measurements(input, self.bad_lines)?),
+        };
+
+        Ok(measurements)
+    }
+
+    fn part1(&self, input: &Self::Input) -> usize {
+        match input {
+            Measurements::Unsigned(measurements) => {
+                part1(measurements, Depth::from_f64(self.tolerance))
+            }
+            Measurements::Signed(measurements) => {
+                part1(measurements, Depth::from_f64(self.tolerance))
+            }
+            Measurements::Decimal(measurements) => {
+                part1(measurements, Depth::from_f64(self.tolerance))
+            }
+        }
+    }
+
+    fn part2(&self, input: &Self::Input) -> usize {
+        match input {
+            Measurements::Unsigned(measurements) => {
+                part2(measurements, Depth::from_f64(self.tolerance))
+            }
+            Measurements::Signed(measurements) => {
+                part2(measurements, Depth::from_f64(self.tolerance))
+            }
+            Measurements::Decimal(measurements) => {
+                part2(measurements, Depth::from_f64(self.tolerance))
+            }
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new(
+                "readings",
+                self.readings,
+                "type of the readings: unsigned, signed or decimal",
+            ),
+            Param::new(
+                "bad_lines",
+                self.bad_lines,
+                "what to do with unreadable lines: fail, skip or interpolate",
+            ),
+            Param::new(
+                "tolerance",
+                self.tolerance,
+                "largest change between windows that doesn't count as one",
+            ),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "readings" => {
+                self.readings =
+                    config::parse_value(name, value, "unsigned, signed or decimal", |_| true)?
+            }
+            "bad_lines" => {
+                self.bad_lines =
+                    config::parse_value(name, value, "fail, skip or interpolate", |_| true)?
+            }
+            "tolerance" => {
+                self.tolerance =
+                    config::parse_value(name, value, "a number that isn't negative", |t: &f64| {
+                        t.is_finite() && *t >= 0.0
+                    })?
+            }
+            _ => return Err(ParamError::unknown(name)),
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day1.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day1::default().parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day1::default().part1(&input), 7);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day1::default().parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day1::default().part2(&input), 5);
+    }
+
+    #[test]
+    fn window_aggregates() {
+        let input = parse_measurements::<usize>(EXAMPLE, BadLines::Fail).unwrap();
+        let trend = |increases, decreases, plateaus, longest_increasing_run| Trend {
+            increases,
+            decreases,
+            plateaus,
+            longest_increasing_run,
+        };
+
+        assert_eq!(analyze(&input, 1, Aggregate::Sum, 0), trend(7, 2, 0, 3));
+        assert_eq!(analyze(&input, 3, Aggregate::Mean, 0), trend(5, 1, 1, 4));
+        assert_eq!(analyze(&input, 3, Aggregate::Min, 0), trend(4, 0, 3, 3));
+        assert_eq!(analyze(&input, 3, Aggregate::Max, 0), trend(3, 0, 4, 2));
+        assert_eq!(analyze(&input, 20, Aggregate::Sum, 0), Trend::default());
+    }
+
+    #[test]
+    fn streams_with_running_counts() {
+        let mut analyzer = Analyzer::<usize>::new(3, Aggregate::Sum);
+        let mut reports = vec![];
+
+        let trend = stream(
+            EXAMPLE.as_bytes(),
+            &mut analyzer,
+            BadLines::Fail,
+            Some(4),
+            |analyzer| reports.push((analyzer.measurements(), analyzer.trend().increases)),
+        )
+        .unwrap();
+
+        assert_eq!(
+            trend,
+            analyze(
+                &parse_measurements::<usize>(EXAMPLE, BadLines::Fail).unwrap(),
+                3,
+                Aggregate::Sum,
+                0
+            )
+        );
+        assert_eq!(reports, [(4, 1), (8, 3)]);
+
+        let input = "1\r\n2\r\nx".as_bytes();
+        let error = stream(input, &mut analyzer, BadLines::Fail, None, |_| {}).unwrap_err();
+        match error {
+            StreamError::Parse(error) => assert_eq!((error.line, error.text.as_str()), (3, "x")),
+            error => panic!("Unexpected error: {}", error),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't report every 0 measurements")]
+    fn reports_need_a_period() {
+        let mut analyzer = Analyzer::<usize>::new(1, Aggregate::Sum);
+
+        let _ = stream(
+            EXAMPLE.as_bytes(),
+            &mut analyzer,
+            BadLines::Fail,
+            Some(0),
+            |_| {},
+        );
+    }
+
+    #[test]
+    fn bad_line_policies() {
+        let input = "-1.5\n\n2.5\nNaN\nx\n4.0\n";
+
+        let error = parse_measurements::<f64>(input, BadLines::Fail).unwrap_err();
+        assert_eq!((error.line, error.kind), (2, ParseErrorKind::Empty));
+
+        let skipped = parse_measurements::<f64>(input, BadLines::Skip).unwrap();
+        assert_eq!(skipped, [-1.5, 2.5, 4.0]);
+
+        let interpolated = parse_measurements::<f64>(input, BadLines::Interpolate).unwrap();
+        assert_eq!(interpolated, [-1.5, 0.5, 2.5, 3.0, 3.5, 4.0]);
+
+        let signed = parse_measurements::<i32>("-3\n\n1", BadLines::Interpolate).unwrap();
+        assert_eq!(signed, [-3, -1, 1]);
+    }
+
+    #[test]
+    fn readings_and_bad_lines_are_configurable() {
+        let input = "-1.5\n\n2.5\n-3\n";
+        let mut day1 = Day1::default();
+
+        assert!(day1.parse(input).is_err());
+
+        day1.set_param("readings", "decimal").unwrap();
+        day1.set_param("bad_lines", "skip").unwrap();
+        let measurements = day1.parse(input).unwrap();
+        assert_eq!(measurements, Measurements::Decimal(vec![-1.5, 2.5, -3.0]));
+        assert_eq!(day1.part1(&measurements), 1);
+
+        day1.set_param("tolerance", "4").unwrap();
+        assert_eq!(day1.part1(&measurements), 0);
+        day1.set_param("tolerance", "3.9").unwrap();
+        assert_eq!(day1.part1(&measurements), 1);
+
+        // Integer readings only ever change by whole amounts.
+        day1.set_param("readings", "signed").unwrap();
+        assert_eq!(day1.part1(&day1.parse("1\n4\n8").unwrap()), 1);
+
+        assert!(day1.set_param("readings", "complex").is_err());
+        assert!(day1.set_param("tolerance", "-1").is_err());
+    }
+
+    #[test]
+    fn tolerance_turns_small_changes_into_plateaus() {
+        let readings = [1.0, 1.05, 1.5, 1.45, 1.0];
+
+        let mut analyzer = Analyzer::new(1, Aggregate::Sum).with_tolerance(0.1);
+        readings.iter().for_each(|reading| analyzer.push(*reading));
+        assert_eq!(
+            (analyzer.trend().increases, analyzer.trend().plateaus),
+            (1, 2)
+        );
+
+        // The tolerance applies to means, not to the sums behind them.
+        let mut analyzer = Analyzer::new(2, Aggregate::Mean).with_tolerance(0.22);
+        readings.iter().for_each(|reading| analyzer.push(*reading));
+        assert_eq!(analyzer.trend().increases, 1);
+    }
+}
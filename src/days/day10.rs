@@ -0,0 +1,244 @@
+use crate::{
+    error::{Invalid, ParseError, ParseErrorKind},
+    Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CharState {
+    Open,
+    Close,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CharType {
+    Parens,   // ()
+    Brackets, // []
+    Braces,   // {}
+    Angle,    // <>
+}
+
+impl CharType {
+    fn error_score(&self) -> i64 {
+        match self {
+            Parens => 3,
+            Brackets => 57,
+            Braces => 1197,
+            Angle => 25137,
+        }
+    }
+
+    fn completion_score(&self) -> i64 {
+        match self {
+            Parens => 1,
+            Brackets => 2,
+            Braces => 3,
+            Angle => 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Char {
+    state: CharState,
+    char_type: CharType,
+}
+
+use CharState::*;
+use CharType::*;
+
+impl Char {
+    fn new(state: CharState, char_type: CharType) -> Self {
+        Self { state, char_type }
+    }
+
+    fn from_char(c: char) -> Option<Self> {
+        let char = match c {
+            '(' => Self::new(Open, Parens),
+            '[' => Self::new(Open, Brackets),
+            '{' => Self::new(Open, Braces),
+            '<' => Self::new(Open, Angle),
+            ')' => Self::new(Close, Parens),
+            ']' => Self::new(Close, Brackets),
+            '}' => Self::new(Close, Braces),
+            '>' => Self::new(Close, Angle),
+            _ => return None,
+        };
+
+        Some(char)
+    }
+
+    fn to_char(self) -> char {
+        match self {
+            Self {
+                state: Open,
+                char_type: Parens,
+            } => '(',
+            Self {
+                state: Open,
+                char_type: Brackets,
+            } => '[',
+            Self {
+                state: Open,
+                char_type: Braces,
+            } => '{',
+            Self {
+                state: Open,
+                char_type: Angle,
+            } => '<',
+            Self {
+                state: Close,
+                char_type: Parens,
+            } => ')',
+            Self {
+                state: Close,
+                char_type: Brackets,
+            } => ']',
+            Self {
+                state: Close,
+                char_type: Braces,
+            } => '}',
+            Self {
+                state: Close,
+                char_type: Angle,
+            } => '>',
+        }
+    }
+
+    #[allow(dead_code)]
+    fn to_string(chars: &[Char]) -> String {
+        chars.iter().map(|c| c.to_char()).collect()
+    }
+}
+
+fn parse_line(line: &str) -> Result<Vec<Char>, Invalid<'_>> {
+    line.char_indices()
+        .map(|(index, c)| {
+            Char::from_char(c).ok_or_else(|| {
+                let text = &line[index..index + c.len_utf8()];
+
+                Invalid::new(text, ParseErrorKind::UnexpectedChar)
+            })
+        })
+        .collect()
+}
+
+/// Returns the first illegal closing character's type, or the chunks left open.
+///
+/// Closing a chunk when none is open is illegal too.
+fn check_line(line: &[Char]) -> Result<Vec<Char>, CharType> {
+    let mut res = vec![];
+    for char in line.iter().copied() {
+        match char.state {
+            Open => {
+                res.push(char);
+            }
+            Close => {
+                let is_bad = match res.last() {
+                    Some(last) => last.state == Open && last.char_type != char.char_type,
+                    None => true,
+                };
+
+                if is_bad {
+                    return Err(char.char_type);
+                } else {
+                    // If we are good, we closed the thing so we can pop the opening one.
+                    res.pop();
+                }
+            }
+        }
+    }
+
+    Ok(res)
+}
+
+pub fn part1(lines: &[Vec<Char>]) -> i64 {
+    let mut res = 0;
+
+    for line in lines.iter() {
+        if let Err(char_type) = check_line(line) {
+            res += char_type.error_score()
+        }
+    }
+
+    res
+}
+
+pub fn part2(lines: &[Vec<Char>]) -> i64 {
+    let mut scores = lines
+        .iter()
+        .filter_map(|line| {
+            if let Ok(remaining) = check_line(line) {
+                let completion_score = remaining
+                    .iter()
+                    .rev()
+                    .map(|c| c.char_type.completion_score())
+                    .fold(0, |acc, elem| acc * 5 + elem);
+
+                Some(completion_score)
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    scores.sort_unstable();
+
+    *scores
+        .get(scores.len() / 2)
+        .expect("Wrong input, no incomplete lines")
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Vec<Char>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    const DAY: u8 = 10;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(parse_line)
+            .collect::<Result<_, _>>()
+            .map_err(|invalid| ParseError::locate(Self::DAY, input, invalid))
+    }
+
+    fn part1(&self, input: &Self::Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> i64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day10.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day10.parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day10.part1(&input), 26397);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day10.parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day10.part2(&input), 288957);
+    }
+
+    #[test]
+    fn closing_nothing_is_corrupted() {
+        let input = Day10.parse("(]\n)\n]()\n((").unwrap();
+
+        assert_eq!(Day10.part1(&input), 57 + 3 + 57);
+        assert_eq!(Day10.part2(&input), 6);
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{
+    config::{self, Param, ParamError},
+    error::{Invalid, ParseError},
+    grid::Grid,
+    Solution,
+};
+
+#[derive(Clone)]
+pub struct Energy {
+    level: u32,
+    flashed: bool,
+}
+
+#[derive(Clone)]
+pub struct Octopuses {
+    grid: Grid<Energy>,
+    /// Octopuses flash once their level goes above this.
+    flash_threshold: u32,
+}
+
+impl Octopuses {
+    fn step(&mut self) -> u32 {
+        for (x, y) in self.grid.positions() {
+            self.inc(x, y)
+        }
+
+        let mut res = 0;
+        for energy in self.grid.iter_mut() {
+            if energy.flashed {
+                res += 1;
+                energy.level = 0;
+                energy.flashed = false;
+            }
+        }
+
+        res
+    }
+
+    /// Raises an octopus' level, along with those of every neighbor of an octopus that flashes
+    /// as a result. Flashes can cascade across the whole grid, so they're kept on a stack
+    /// rather than followed recursively.
+    fn inc(&mut self, x: usize, y: usize) {
+        let mut pending = vec![(x, y)];
+
+        while let Some((x, y)) = pending.pop() {
+            let e = &mut self.grid[(x, y)];
+            e.level += 1;
+
+            if e.level > self.flash_threshold && !e.flashed {
+                e.flashed = true;
+                pending.extend(self.grid.neighbors8(x, y));
+            }
+        }
+    }
+
+    pub fn parse(input: &str, flash_threshold: u32) -> Result<Self, Invalid<'_>> {
+        let grid = Grid::parse_digits(input)?.map(|level| Energy {
+            level: *level,
+            flashed: false,
+        });
+
+        Ok(Self {
+            grid,
+            flash_threshold,
+        })
+    }
+}
+
+pub fn part1(octopuses: &Octopuses, steps: u32) -> u32 {
+    let mut grid = octopuses.clone();
+
+    let mut res = 0;
+    for _ in 0..steps {
+        res += grid.step();
+    }
+
+    res
+}
+
+/// The first step after which every octopus just flashed, if it comes within `max_steps`.
+pub fn synchronized_step(octopuses: &Octopuses, max_steps: u32) -> Option<u32> {
+    let mut grid = octopuses.clone();
+
+    let mut step = 0;
+    loop {
+        let all_zero = grid.grid.iter().all(|e| e.level == 0);
+
+        if all_zero {
+            return Some(step);
+        }
+        if step == max_steps {
+            return None;
+        }
+
+        grid.step();
+        step += 1;
+    }
+}
+
+pub fn part2(octopuses: &Octopuses) -> u32 {
+    synchronized_step(octopuses, u32::MAX).expect("Wrong input, octopuses never synchronize")
+}
+
+pub struct Day11 {
+    /// Steps simulated by part 1.
+    pub steps: u32,
+    pub flash_threshold: u32,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Self {
+            steps: 100,
+            flash_threshold: 9,
+        }
+    }
+}
+
+impl Solution for Day11 {
+    type Input = Octopuses;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const DAY: u8 = 11;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Octopuses::parse(input, self.flash_threshold)
+            .map_err(|invalid| ParseError::locate(Self::DAY, input, invalid))
+    }
+
+    fn part1(&self, input: &Self::Input) -> u32 {
+        part1(input, self.steps)
+    }
+
+    fn part2(&self, input: &Self::Input) -> u32 {
+        part2(input)
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("steps", self.steps, "steps simulated by part 1"),
+            Param::new(
+                "flash_threshold",
+                self.flash_threshold,
+                "energy level an octopus has to go above to flash",
+            ),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "steps" => {
+                self.steps = config::parse_value(name, value, "a number of steps", |_| true)?
+            }
+            "flash_threshold" => {
+                self.flash_threshold =
+                    config::parse_value(name, value, "an energy level", |_| true)?
+            }
+            _ => return Err(ParamError::unknown(name)),
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day11.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day11::default().parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day11::default().part1(&input), 1656);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day11::default().parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day11::default().part2(&input), 195);
+    }
+}
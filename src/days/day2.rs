@@ -0,0 +1,690 @@
+use std::{
+    collections::HashMap,
+    convert::TryFrom,
+    fmt::{self, Write},
+};
+
+use crate::{
+    error::{self, Invalid, ParseError, ParseErrorKind},
+    Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Forward(usize),
+    Down(usize),
+    Up(usize),
+    Left(usize),
+    Right(usize),
+}
+use Instruction::*;
+
+impl Instruction {
+    /// Parses a line like:
+    ///  "forward 5"
+    pub fn parse(line: &str) -> Result<Self, Invalid<'_>> {
+        let (instruction_name, value) = line.split_once(' ').ok_or_else(|| {
+            Invalid::new(line, ParseErrorKind::Expected("`<instruction> <value>`"))
+        })?;
+        let value = error::parse_number(value)?;
+
+        let instruction = match instruction_name {
+            "forward" => Forward(value),
+            "down" => Down(value),
+            "up" => Up(value),
+            "left" => Left(value),
+            "right" => Right(value),
+            _ => {
+                return Err(Invalid::new(
+                    instruction_name,
+                    ParseErrorKind::UnknownInstruction,
+                ))
+            }
+        };
+
+        Ok(instruction)
+    }
+}
+
+/// Courses longer than this are rejected, so that nested repeats can't exhaust memory.
+pub const MAX_COURSE_LENGTH: usize = 10_000_000;
+
+/// How deep blocks can be nested, so that parsing them can't exhaust the stack.
+pub const MAX_NESTING: usize = 100;
+
+/// Parses a course, expanding repeat blocks and macros into plain instructions.
+///
+/// Besides one instruction per line, courses can have:
+///
+/// ```text
+/// # comments, on their own line or after anything else
+/// repeat 3 {
+///     forward 2
+/// }
+/// macro dive {
+///     down 5
+///     forward 1
+/// }
+/// dive
+/// ```
+///
+/// Macros can use macros defined before them, but not themselves.
+pub fn parse_course(input: &str) -> Result<Vec<Instruction>, Invalid<'_>> {
+    let mut parser = CourseParser {
+        lines: input.lines(),
+        macros: HashMap::new(),
+    };
+
+    parser.block(None, 0)
+}
+
+struct CourseParser<'a, L> {
+    lines: L,
+    macros: HashMap<&'a str, Vec<Instruction>>,
+}
+
+impl<'a, L: Iterator<Item = &'a str>> CourseParser<'a, L> {
+    /// Parses lines until the `}` closing `opening`, or until the end without one.
+    ///
+    /// `depth` counts the blocks `opening` is nested in, itself included.
+    fn block(
+        &mut self,
+        opening: Option<&'a str>,
+        depth: usize,
+    ) -> Result<Vec<Instruction>, Invalid<'a>> {
+        if let Some(opening) = opening.filter(|_| depth > MAX_NESTING) {
+            return Err(Invalid::new(
+                opening,
+                ParseErrorKind::TooMany {
+                    max: MAX_NESTING,
+                    what: "nested blocks",
+                },
+            ));
+        }
+
+        let mut instructions = vec![];
+
+        while let Some(line) = self.lines.next() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+
+            if line.is_empty() {
+                continue;
+            }
+            if line == "}" {
+                return match opening {
+                    Some(_) => Ok(instructions),
+                    None => Err(Invalid::new(line, ParseErrorKind::UnexpectedChar)),
+                };
+            }
+
+            let header = line.strip_suffix('{').map(str::trim_end);
+            let keyword = header.and_then(|header| header.split_once(' '));
+
+            match keyword {
+                Some(("repeat", count)) => {
+                    let count: usize = error::parse_number(count.trim())?;
+                    let body = self.block(Some(line), depth + 1)?;
+
+                    if body.len().saturating_mul(count) > MAX_COURSE_LENGTH - instructions.len() {
+                        return Err(Invalid::new(line, TOO_LONG));
+                    }
+                    // Bounded by the length of the output, even for empty bodies.
+                    let repeated = body.iter().cycle().take(body.len() * count);
+                    instructions.extend(repeated);
+                }
+                Some(("macro", name)) => {
+                    let name = name.trim();
+
+                    if !is_macro_name(name) {
+                        return Err(Invalid::new(
+                            name,
+                            ParseErrorKind::Expected("a macro name made of letters, digits or `_`"),
+                        ));
+                    }
+
+                    let body = self.block(Some(line), depth + 1)?;
+                    self.macros.insert(name, body);
+                }
+                _ => match self.macros.get(line) {
+                    Some(body) if body.len() <= MAX_COURSE_LENGTH - instructions.len() => {
+                        instructions.extend_from_slice(body)
+                    }
+                    Some(_) => return Err(Invalid::new(line, TOO_LONG)),
+                    None => {
+                        if instructions.len() == MAX_COURSE_LENGTH {
+                            return Err(Invalid::new(line, TOO_LONG));
+                        }
+                        instructions.push(Instruction::parse(line)?);
+                    }
+                },
+            }
+        }
+
+        match opening {
+            Some(opening) => Err(Invalid::new(
+                opening,
+                ParseErrorKind::Expected("a block closed by `}`"),
+            )),
+            None => Ok(instructions),
+        }
+    }
+}
+
+const TOO_LONG: ParseErrorKind = ParseErrorKind::TooMany {
+    max: MAX_COURSE_LENGTH,
+    what: "instructions in a course",
+};
+
+fn is_macro_name(name: &str) -> bool {
+    let keywords = ["forward", "down", "up", "left", "right", "repeat", "macro"];
+
+    !name.is_empty()
+        && !keywords.contains(&name)
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Where the submarine is. Depth grows downwards, so it's negative above the surface.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Position {
+    pub x: i64,
+    pub depth: i64,
+    pub aim: i64,
+    /// Sideways position, moved by `left` and `right`. Positive is to the right.
+    pub lateral: i64,
+}
+
+/// A model of how the submarine moves when given an instruction.
+pub trait Navigator {
+    /// Identifies the model when picking one from `Navigators`.
+    fn name(&self) -> &str;
+
+    /// Where `instruction` takes the submarine from `position`, unless that overflows.
+    fn steer(&self, position: Position, instruction: &Instruction) -> Option<Position>;
+}
+
+/// Part 1 rules: `down` and `up` change the depth directly.
+pub struct Direct;
+
+impl Navigator for Direct {
+    fn name(&self) -> &str {
+        "direct"
+    }
+
+    fn steer(&self, position: Position, instruction: &Instruction) -> Option<Position> {
+        let mut next = position;
+
+        match *instruction {
+            Forward(value) => next.x = position.x.checked_add(signed(value)?)?,
+            Down(value) => next.depth = position.depth.checked_add(signed(value)?)?,
+            Up(value) => next.depth = position.depth.checked_sub(signed(value)?)?,
+            Left(value) => next.lateral = position.lateral.checked_sub(signed(value)?)?,
+            Right(value) => next.lateral = position.lateral.checked_add(signed(value)?)?,
+        }
+
+        Some(next)
+    }
+}
+
+/// Part 2 rules: `down` and `up` change the aim, and moving forward follows it.
+pub struct Aimed;
+
+impl Navigator for Aimed {
+    fn name(&self) -> &str {
+        "aimed"
+    }
+
+    fn steer(&self, position: Position, instruction: &Instruction) -> Option<Position> {
+        let mut next = position;
+
+        match *instruction {
+            Forward(value) => {
+                let value = signed(value)?;
+
+                next.x = position.x.checked_add(value)?;
+                next.depth = position
+                    .depth
+                    .checked_add(position.aim.checked_mul(value)?)?;
+            }
+            Down(value) => next.aim = position.aim.checked_add(signed(value)?)?,
+            Up(value) => next.aim = position.aim.checked_sub(signed(value)?)?,
+            // Moving sideways doesn't involve the aim.
+            Left(value) => next.lateral = position.lateral.checked_sub(signed(value)?)?,
+            Right(value) => next.lateral = position.lateral.checked_add(signed(value)?)?,
+        }
+
+        Some(next)
+    }
+}
+
+fn signed(value: usize) -> Option<i64> {
+    i64::try_from(value).ok()
+}
+
+/// The navigators available by name, starting with `Direct` and `Aimed`.
+pub struct Navigators(Vec<Box<dyn Navigator>>);
+
+impl Navigators {
+    /// Adds a navigator, replacing any other with the same name.
+    pub fn register(&mut self, navigator: Box<dyn Navigator>) {
+        self.0.retain(|other| other.name() != navigator.name());
+        self.0.push(navigator);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn Navigator> {
+        self.iter().find(|navigator| navigator.name() == name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Navigator> {
+        self.0.iter().map(|navigator| navigator.as_ref())
+    }
+}
+
+impl Default for Navigators {
+    fn default() -> Self {
+        Self(vec![Box::new(Direct), Box::new(Aimed)])
+    }
+}
+
+/// How following a course went.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Voyage {
+    /// Where the submarine ended up, or where it was before overflowing.
+    pub position: Position,
+    /// Instructions (counting from 0) that took the submarine above the surface.
+    pub surfaced: Vec<usize>,
+    /// The instruction whose result didn't fit in an `i64`, which ended the voyage.
+    pub overflowed: Option<usize>,
+}
+
+impl Voyage {
+    /// The puzzle's answer: horizontal position times depth, unless something overflowed.
+    pub fn product(&self) -> Option<i64> {
+        if self.overflowed.is_some() {
+            return None;
+        }
+
+        self.position.x.checked_mul(self.position.depth)
+    }
+}
+
+impl PartialEq<i64> for Voyage {
+    fn eq(&self, other: &i64) -> bool {
+        self.product() == Some(*other)
+    }
+}
+
+/// The product, or where the voyage overflowed. Instructions are counted from 1.
+impl fmt::Display for Voyage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.overflowed, self.product()) {
+            (Some(step), _) => write!(f, "overflow at instruction {}", step + 1),
+            (None, Some(product)) => write!(f, "{}", product),
+            (None, None) => write!(
+                f,
+                "overflow multiplying {} by {}",
+                self.position.x, self.position.depth
+            ),
+        }
+    }
+}
+
+/// Follows the instructions from the surface, moving as `navigator` says.
+pub fn sail(instructions: &[Instruction], navigator: &dyn Navigator) -> Voyage {
+    follow(instructions, navigator, |_| {})
+}
+
+/// Like `sail`, but also keeps every position along the way.
+pub fn record(instructions: &[Instruction], navigator: &dyn Navigator) -> (Voyage, Trajectory) {
+    let mut positions = vec![Position::default()];
+    let voyage = follow(instructions, navigator, |position| positions.push(position));
+
+    (voyage, Trajectory(positions))
+}
+
+/// Sails, calling `visit` with the position after each instruction.
+fn follow<F>(instructions: &[Instruction], navigator: &dyn Navigator, mut visit: F) -> Voyage
+where
+    F: FnMut(Position),
+{
+    let mut voyage = Voyage {
+        position: Position::default(),
+        surfaced: vec![],
+        overflowed: None,
+    };
+
+    for (step, instruction) in instructions.iter().enumerate() {
+        let next = match navigator.steer(voyage.position, instruction) {
+            Some(next) => next,
+            None => {
+                voyage.overflowed = Some(step);
+                break;
+            }
+        };
+
+        if next.depth < 0 && voyage.position.depth >= 0 {
+            voyage.surfaced.push(step);
+        }
+        voyage.position = next;
+        visit(next);
+    }
+
+    voyage
+}
+
+/// Every position the submarine went through, starting at the surface before the first
+/// instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trajectory(Vec<Position>);
+
+impl Trajectory {
+    /// Positions by step, step 0 being the starting point.
+    pub fn positions(&self) -> &[Position] {
+        &self.0
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut result = String::from("step,x,depth,aim,lateral");
+
+        for (step, position) in self.0.iter().enumerate() {
+            write!(
+                result,
+                "\n{},{},{},{},{}",
+                step, position.x, position.depth, position.aim, position.lateral
+            )
+            .unwrap();
+        }
+
+        result
+    }
+
+    /// Plots depth against horizontal position, with the surface as a horizontal line.
+    ///
+    /// Both axes are stretched to fill the image, since depths usually dwarf distances.
+    pub fn to_svg(&self) -> String {
+        let min_x = self.0.iter().map(|p| p.x).min().unwrap_or(0);
+        let max_x = self.0.iter().map(|p| p.x).max().unwrap_or(0);
+        let min_depth = self.0.iter().map(|p| p.depth).min().unwrap_or(0).min(0);
+        let max_depth = self.0.iter().map(|p| p.depth).max().unwrap_or(0);
+
+        let mut path = String::new();
+        for (step, position) in self.0.iter().enumerate() {
+            let command = if step == 0 { 'M' } else { 'L' };
+            write!(path, "{}{} {} ", command, position.x, position.depth).unwrap();
+        }
+
+        format!(
+            concat!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"800\" height=\"400\" ",
+                "viewBox=\"{} {} {} {}\" preserveAspectRatio=\"none\">\n",
+                "  <line x1=\"{}\" y1=\"0\" x2=\"{}\" y2=\"0\" stroke=\"steelblue\" ",
+                "vector-effect=\"non-scaling-stroke\"/>\n",
+                "  <path d=\"{}\" fill=\"none\" stroke=\"black\" ",
+                "vector-effect=\"non-scaling-stroke\"/>\n",
+                "</svg>"
+            ),
+            min_x,
+            min_depth,
+            (max_x - min_x).max(1),
+            (max_depth - min_depth).max(1),
+            min_x,
+            max_x,
+            path.trim_end(),
+        )
+    }
+}
+
+/// Follows the same instructions with every navigator.
+pub fn compare<'a>(
+    instructions: &[Instruction],
+    navigators: &'a Navigators,
+) -> Vec<(&'a str, Voyage)> {
+    navigators
+        .iter()
+        .map(|navigator| (navigator.name(), sail(instructions, navigator)))
+        .collect()
+}
+
+/// Lists how the course goes with every navigator: the answer, the instructions that took the
+/// submarine above the surface and the one that overflowed, all counted from 1.
+pub fn report(instructions: &[Instruction], navigators: &Navigators) -> String {
+    let steps = |steps: &[usize]| match steps {
+        [] => "none".to_owned(),
+        steps => steps
+            .iter()
+            .map(|step| (step + 1).to_string())
+            .collect::<Vec<_>>()
+            .join(", "),
+    };
+    let mut result = String::new();
+
+    for (name, voyage) in compare(instructions, navigators) {
+        let overflowed = voyage.overflowed.into_iter().collect::<Vec<_>>();
+
+        writeln!(result, "{}", name).unwrap();
+        writeln!(result, "  answer      {}", voyage).unwrap();
+        writeln!(result, "  surfaced    {}", steps(&voyage.surfaced)).unwrap();
+        writeln!(result, "  overflowed  {}", steps(&overflowed)).unwrap();
+    }
+
+    result
+}
+
+pub fn part1(instructions: &[Instruction]) -> Voyage {
+    sail(instructions, &Direct)
+}
+
+pub fn part2(instructions: &[Instruction]) -> Voyage {
+    sail(instructions, &Aimed)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Instruction>;
+    type Answer1 = Voyage;
+    type Answer2 = Voyage;
+
+    const DAY: u8 = 2;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_course(input).map_err(|invalid| ParseError::locate(Self::DAY, input, invalid))
+    }
+
+    fn part1(&self, input: &Self::Input) -> Voyage {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Voyage {
+        part2(input)
+    }
+
+    fn report(&self, _text: &str, input: &Self::Input) -> Option<String> {
+        Some(report(input, &Navigators::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day2.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day2.parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day2.part1(&input), 150);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day2.parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day2.part2(&input), 900);
+    }
+
+    #[test]
+    fn course_language() {
+        let course = "\
+# A dive, then a zigzag
+macro dive {
+    down 5 # steeper
+    forward 1
+}
+dive
+repeat 2 {
+    left 1
+    repeat 2 {
+        right 2
+    }
+}
+";
+
+        let input = Day2.parse(course).unwrap();
+
+        assert_eq!(
+            input,
+            [
+                Down(5),
+                Forward(1),
+                Left(1),
+                Right(2),
+                Right(2),
+                Left(1),
+                Right(2),
+                Right(2)
+            ]
+        );
+        assert_eq!(sail(&input, &Direct).position.lateral, 6);
+    }
+
+    #[test]
+    fn course_errors() {
+        let error = |course| Day2.parse(course).unwrap_err();
+
+        assert_eq!(error("repeat 2 {\nup 1").line, 1);
+        assert_eq!(error("up 1\n}").kind, ParseErrorKind::UnexpectedChar);
+        // Macros can't call themselves, so `a` is just a malformed instruction there.
+        assert_eq!(error("macro a {\na\n}").line, 2);
+        assert_eq!(
+            error("repeat 100000 {\nrepeat 100000 {\nup 1\n}\n}").kind,
+            TOO_LONG
+        );
+        assert_eq!(
+            Day2.parse("repeat 18446744073709551615 {\n# nothing\n}\nup 1"),
+            Ok(vec![Up(1)])
+        );
+
+        let nested = |depth| "repeat 1 {\n".repeat(depth) + "up 1" + &"\n}".repeat(depth);
+        assert_eq!(Day2.parse(&nested(MAX_NESTING)).unwrap(), [Up(1)]);
+        let error = error(&nested(200_000));
+        assert_eq!(
+            (error.line, error.kind),
+            (
+                MAX_NESTING + 1,
+                ParseErrorKind::TooMany {
+                    max: MAX_NESTING,
+                    what: "nested blocks"
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn records_trajectory() {
+        let input = Day2.parse(EXAMPLE).unwrap();
+
+        let (voyage, trajectory) = record(&input, &Aimed);
+
+        assert_eq!(trajectory.positions().len(), input.len() + 1);
+        assert_eq!(trajectory.positions().last(), Some(&voyage.position));
+        assert!(trajectory
+            .to_csv()
+            .starts_with("step,x,depth,aim,lateral\n0,0,0,0,0\n1,5,0,0,0\n2,5,0,5,0\n"));
+
+        let svg = trajectory.to_svg();
+        assert!(svg.contains("viewBox=\"0 0 15 60\""));
+        assert!(svg.contains("d=\"M0 0 L5 0 L5 0 L13 40 "));
+    }
+
+    #[test]
+    fn reports_surfacing_and_overflow() {
+        let input = Day2.parse("down 2\nup 3\ndown 1\nup 1\nforward 2").unwrap();
+
+        let voyage = sail(&input, &Direct);
+        assert_eq!(voyage.surfaced, [1, 3]);
+        assert_eq!(voyage.product(), Some(-2));
+
+        let input = Day2
+            .parse("down 9223372036854775807\nforward 1\nforward 1")
+            .unwrap();
+
+        let voyage = sail(&input, &Aimed);
+        assert_eq!(voyage.overflowed, Some(2));
+        assert_eq!(voyage.position.depth, i64::MAX);
+        assert_eq!(voyage.product(), None);
+        assert_eq!(voyage.to_string(), "overflow at instruction 3");
+
+        let input = Day2.parse("down 9223372036854775807\nforward 2").unwrap();
+        assert_eq!(
+            Day2.part1(&input).to_string(),
+            "overflow multiplying 2 by 9223372036854775807"
+        );
+
+        let input = Day2.parse("up 5\nforward 3").unwrap();
+        assert_eq!(Day2.part1(&input).to_string(), "-15");
+        assert_eq!(
+            Solution::report(&Day2, "", &input).unwrap(),
+            concat!(
+                "direct\n",
+                "  answer      -15\n",
+                "  surfaced    1\n",
+                "  overflowed  none\n",
+                "aimed\n",
+                "  answer      -45\n",
+                "  surfaced    2\n",
+                "  overflowed  none\n",
+            )
+        );
+    }
+
+    /// Swaps `down` and `up`, on top of the part 2 rules.
+    struct Inverted;
+
+    impl Navigator for Inverted {
+        fn name(&self) -> &str {
+            "inverted"
+        }
+
+        fn steer(&self, position: Position, instruction: &Instruction) -> Option<Position> {
+            let swapped = match *instruction {
+                Down(value) => Up(value),
+                Up(value) => Down(value),
+                other => other,
+            };
+
+            Aimed.steer(position, &swapped)
+        }
+    }
+
+    #[test]
+    fn registered_navigators_sail_the_same_course() {
+        let input = Day2.parse(EXAMPLE).unwrap();
+        let mut navigators = Navigators::default();
+        navigators.register(Box::new(Inverted));
+
+        let products = compare(&input, &navigators)
+            .into_iter()
+            .map(|(name, voyage)| (name, voyage.product()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            products,
+            [
+                ("direct", Some(150)),
+                ("aimed", Some(900)),
+                ("inverted", Some(-900))
+            ]
+        );
+    }
+}
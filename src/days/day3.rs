@@ -0,0 +1,958 @@
+use std::{error::Error, fmt, iter, ops::Range, str::FromStr};
+
+use crate::{
+    config::{self, Param, ParamError},
+    error::{Invalid, ParseError, ParseErrorKind},
+    Solution,
+};
+
+/// The symbols digits are written with, from the lowest to the highest.
+///
+/// Reports are usually binary, but any radix or set of symbols works the same way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alphabet {
+    symbols: Vec<char>,
+}
+
+impl Alphabet {
+    /// Needs at least 2 symbols, all different.
+    pub fn new(symbols: &str) -> Option<Self> {
+        let symbols: Vec<char> = symbols.chars().collect();
+        let unique = symbols
+            .iter()
+            .enumerate()
+            .all(|(index, symbol)| !symbols[..index].contains(symbol));
+
+        if symbols.len() < 2 || !unique {
+            return None;
+        }
+
+        Some(Self { symbols })
+    }
+
+    pub fn binary() -> Self {
+        Self::new("01").unwrap()
+    }
+
+    /// Digits followed by lowercase letters, like `u32::from_str_radix`, for 2 to 36.
+    pub fn with_radix(radix: usize) -> Option<Self> {
+        Self::new("0123456789abcdefghijklmnopqrstuvwxyz".get(..radix)?)
+    }
+
+    pub fn radix(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn symbols(&self) -> &[char] {
+        &self.symbols
+    }
+
+    /// The most digits a number can have and still fit in a `u128`.
+    pub fn max_width(&self) -> usize {
+        let radix = self.radix() as u128;
+        let mut largest: u128 = 0;
+        let mut width = 0;
+
+        while let Some(next) = largest
+            .checked_mul(radix)
+            .and_then(|largest| largest.checked_add(radix - 1))
+        {
+            largest = next;
+            width += 1;
+        }
+
+        width
+    }
+
+    /// Writes `number` with exactly `width` digits.
+    pub fn format(&self, mut number: u128, width: usize) -> String {
+        let radix = self.radix() as u128;
+        let mut digits = Vec::with_capacity(width);
+
+        for _ in 0..width {
+            digits.push(self.symbols[(number % radix) as usize]);
+            number /= radix;
+        }
+
+        digits.into_iter().rev().collect()
+    }
+
+    /// Parses a line like:
+    ///  "00100"
+    ///
+    /// It must be short enough to fit, see `max_width`.
+    fn parse<'a>(&self, line: &'a str) -> Result<u128, Invalid<'a>> {
+        let radix = self.radix() as u128;
+        let mut number = 0;
+
+        for (index, char) in line.char_indices() {
+            let digit = self
+                .symbols
+                .iter()
+                .position(|symbol| *symbol == char)
+                .ok_or_else(|| {
+                    let text = &line[index..index + char.len_utf8()];
+
+                    Invalid::new(text, ParseErrorKind::UnexpectedChar)
+                })?;
+
+            number = number * radix + digit as u128;
+        }
+
+        Ok(number)
+    }
+}
+
+impl fmt::Display for Alphabet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(&self.symbols.iter().collect::<String>())
+    }
+}
+
+impl FromStr for Alphabet {
+    type Err = ();
+
+    fn from_str(symbols: &str) -> Result<Self, ()> {
+        Self::new(symbols).ok_or(())
+    }
+}
+
+/// The diagnostic report: numbers that all have the same number of digits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub alphabet: Alphabet,
+    pub width: usize,
+    pub numbers: Vec<u128>,
+}
+
+impl Report {
+    /// Parses one number per line, taking the width from the first one.
+    pub fn parse<'a>(input: &'a str, alphabet: &Alphabet) -> Result<Self, Invalid<'a>> {
+        let first = input
+            .lines()
+            .next()
+            .filter(|line| !line.is_empty())
+            .ok_or_else(|| Invalid::new(input, ParseErrorKind::Empty))?;
+        let width = first.chars().count();
+
+        if width > alphabet.max_width() {
+            return Err(Invalid::new(
+                first,
+                ParseErrorKind::Expected("numbers that fit in 128 bits"),
+            ));
+        }
+
+        let numbers = input
+            .lines()
+            .map(|line| {
+                let found = line.chars().count();
+
+                if found != width {
+                    return Err(Invalid::new(
+                        line,
+                        ParseErrorKind::WrongLength {
+                            expected: width,
+                            found,
+                        },
+                    ));
+                }
+
+                alphabet.parse(line)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            alphabet: alphabet.clone(),
+            width,
+            numbers,
+        })
+    }
+
+    /// What a 1 at `position` is worth, counting positions from the left.
+    fn place(&self, position: usize) -> u128 {
+        let power = right_hand_index(self.width, position) as u32;
+
+        (self.alphabet.radix() as u128).pow(power)
+    }
+
+    fn digit(&self, number: u128, place: u128) -> usize {
+        (number / place % self.alphabet.radix() as u128) as usize
+    }
+
+    /// How many of the numbers have each digit at `position`.
+    fn counts(&self, numbers: &[u128], position: usize) -> Vec<usize> {
+        let place = self.place(position);
+        let mut counts = vec![0; self.alphabet.radix()];
+
+        for number in numbers {
+            counts[self.digit(*number, place)] += 1;
+        }
+
+        counts
+    }
+}
+
+/// What to do where several digits are equally common.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TiePolicy {
+    /// Pick the highest of the tied digits, which is 1 in binary.
+    PreferHighest,
+    /// Pick the lowest of the tied digits, which is 0 in binary.
+    PreferLowest,
+    /// Give up, as the report isn't supposed to have a tie there.
+    Error,
+    /// Keep the candidates with any of the tied digits. Only rating searches can do this.
+    KeepAll,
+}
+
+impl TiePolicy {
+    /// The policy picking from the other end, if it picks at all.
+    fn reversed(self) -> Self {
+        match self {
+            TiePolicy::PreferHighest => TiePolicy::PreferLowest,
+            TiePolicy::PreferLowest => TiePolicy::PreferHighest,
+            other => other,
+        }
+    }
+
+    /// Settles on one of the `tied` digits (lowest first), or all of them for `KeepAll`.
+    fn settle<F>(self, mut tied: Vec<usize>, error: F) -> Result<Vec<usize>, DiagnosticError>
+    where
+        F: FnOnce() -> DiagnosticError,
+    {
+        if tied.len() == 1 {
+            return Ok(tied);
+        }
+
+        match self {
+            TiePolicy::PreferHighest => Ok(tied.split_off(tied.len() - 1)),
+            TiePolicy::PreferLowest => Ok(vec![tied[0]]),
+            TiePolicy::Error => Err(error()),
+            TiePolicy::KeepAll => Ok(tied),
+        }
+    }
+}
+
+impl fmt::Display for TiePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            TiePolicy::PreferHighest => "highest",
+            TiePolicy::PreferLowest => "lowest",
+            TiePolicy::Error => "error",
+            TiePolicy::KeepAll => "keep_all",
+        };
+
+        f.pad(name)
+    }
+}
+
+impl FromStr for TiePolicy {
+    type Err = ();
+
+    /// Also accepts `one` and `zero`, the names of the binary digits.
+    fn from_str(name: &str) -> Result<Self, ()> {
+        match name {
+            "highest" | "one" => Ok(TiePolicy::PreferHighest),
+            "lowest" | "zero" => Ok(TiePolicy::PreferLowest),
+            "error" => Ok(TiePolicy::Error),
+            "keep_all" => Ok(TiePolicy::KeepAll),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiagnosticError {
+    /// Several digits were equally common at a position, counting from the left.
+    Tie {
+        value: &'static str,
+        position: usize,
+    },
+    /// Keeping all candidates on ties left several different numbers in the end.
+    Ambiguous {
+        value: &'static str,
+        candidates: Vec<u128>,
+    },
+}
+
+impl fmt::Display for DiagnosticError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DiagnosticError::Tie { value, position } => write!(
+                f,
+                "digits are equally common at position {} of the {}",
+                position, value
+            ),
+            DiagnosticError::Ambiguous { value, candidates } => write!(
+                f,
+                "the {} is ambiguous, {} candidates are left",
+                value,
+                candidates.len()
+            ),
+        }
+    }
+}
+
+impl Error for DiagnosticError {}
+
+/// The most (or least) common digits, lowest first.
+///
+/// Digits no number has only count when `absent` is set, and then they're the least common.
+fn extremes(counts: &[usize], most_common: bool, absent: bool) -> Vec<usize> {
+    let counted = |count: &usize| absent || *count > 0;
+    let candidates = counts.iter().copied().filter(counted);
+    let extreme = if most_common {
+        candidates.max()
+    } else {
+        candidates.min()
+    };
+
+    counts
+        .iter()
+        .enumerate()
+        .filter(|(_, count)| counted(count) && Some(**count) == extreme)
+        .map(|(digit, _)| digit)
+        .collect()
+}
+
+/// The gamma and epsilon rates, along with the counts they come from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PowerConsumption {
+    /// How many numbers have each digit, for every position from the left.
+    pub counts: Vec<Vec<usize>>,
+    pub gamma: u128,
+    pub epsilon: u128,
+}
+
+impl PowerConsumption {
+    pub fn product(&self) -> Product {
+        Product::new(self.gamma, self.epsilon)
+    }
+}
+
+/// The product of two `u128`s, which needs up to 256 bits.
+///
+/// Both answers multiply numbers as wide as the report, so they only fit in a `u128` for
+/// reports of up to 64 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Product {
+    high: u128,
+    low: u128,
+}
+
+impl Product {
+    pub fn new(a: u128, b: u128) -> Self {
+        const HALF: u32 = 64;
+        const MASK: u128 = u64::MAX as u128;
+
+        let (a_high, a_low) = (a >> HALF, a & MASK);
+        let (b_high, b_low) = (b >> HALF, b & MASK);
+        let low_low = a_low * b_low;
+        let low_high = a_low * b_high;
+        let high_low = a_high * b_low;
+        let middle = (low_low >> HALF) + (low_high & MASK) + (high_low & MASK);
+
+        Self {
+            high: a_high * b_high + (low_high >> HALF) + (high_low >> HALF) + (middle >> HALF),
+            low: (low_low & MASK) | (middle << HALF),
+        }
+    }
+}
+
+impl PartialEq<u128> for Product {
+    fn eq(&self, other: &u128) -> bool {
+        self.high == 0 && self.low == *other
+    }
+}
+
+impl fmt::Display for Product {
+    /// Divides the product by 10^19 until nothing is left, which gives its digits in chunks
+    /// that each fit in a `u64`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const CHUNK: u128 = 10_000_000_000_000_000_000;
+
+        if self.high == 0 {
+            return write!(f, "{}", self.low);
+        }
+
+        // Most significant first, 64 bits each.
+        let mut limbs = [
+            (self.high >> 64) as u64,
+            self.high as u64,
+            (self.low >> 64) as u64,
+            self.low as u64,
+        ];
+        let mut chunks = vec![];
+
+        while limbs.iter().any(|limb| *limb != 0) {
+            let mut remainder = 0;
+
+            for limb in limbs.iter_mut() {
+                let dividend = (remainder << 64) | u128::from(*limb);
+                *limb = (dividend / CHUNK) as u64;
+                remainder = dividend % CHUNK;
+            }
+
+            chunks.push(remainder);
+        }
+
+        let mut chunks = chunks.iter().rev();
+        write!(f, "{}", chunks.next().unwrap())?;
+        for chunk in chunks {
+            write!(f, "{:019}", chunk)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Gamma takes the most common digit of each position, and epsilon the least common one.
+///
+/// Digits that no number has are the least common ones. On ties gamma follows `ties` and
+/// epsilon picks from the other end, so that in binary epsilon is always gamma's complement.
+/// `KeepAll` doesn't give them a digit, so with it ties are errors.
+pub fn power_consumption(
+    report: &Report,
+    ties: TiePolicy,
+) -> Result<PowerConsumption, DiagnosticError> {
+    let ties = match ties {
+        TiePolicy::KeepAll => TiePolicy::Error,
+        ties => ties,
+    };
+    let counts: Vec<Vec<usize>> = (0..report.width)
+        .map(|position| report.counts(&report.numbers, position))
+        .collect();
+
+    let radix = report.alphabet.radix() as u128;
+    let (mut gamma, mut epsilon) = (0, 0);
+
+    for (position, counts) in counts.iter().enumerate() {
+        let tie = || DiagnosticError::Tie {
+            value: "power consumption",
+            position,
+        };
+        let most_common = ties.settle(extremes(counts, true, true), tie)?[0];
+        let least_common = ties.reversed().settle(extremes(counts, false, true), tie)?[0];
+
+        gamma = gamma * radix + most_common as u128;
+        epsilon = epsilon * radix + least_common as u128;
+    }
+
+    Ok(PowerConsumption {
+        counts,
+        gamma,
+        epsilon,
+    })
+}
+
+/// One position of a rating search, counting the remaining candidates only.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    /// How many candidates have each digit.
+    pub counts: Vec<usize>,
+    /// The digits of the candidates that were kept, lowest first.
+    pub kept: Vec<usize>,
+    /// Candidates left after this step.
+    pub remaining: usize,
+}
+
+/// How a rating was found, from the first position to the one leaving a single candidate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Search {
+    pub steps: Vec<Step>,
+    pub rating: u128,
+}
+
+/// Finds a rating by narrowing down the sorted numbers one digit at a time, from the left.
+///
+/// Once sorted, the numbers sharing the digits seen so far are contiguous, ordered by their
+/// next digit. So each position takes a few binary searches rather than a pass over the
+/// candidates. Keeping several digits on a tie splits the candidates into several such
+/// ranges. A digit no candidate has is never kept.
+fn search(
+    report: &Report,
+    sorted: &[u128],
+    most_common: bool,
+    ties: TiePolicy,
+    value: &'static str,
+) -> Result<Search, DiagnosticError> {
+    let radix = report.alphabet.radix();
+    let mut candidates: Vec<Range<usize>> = iter::once(0..sorted.len()).collect();
+    let mut steps = vec![];
+
+    for position in 0..report.width {
+        if candidates.iter().map(|range| range.len()).sum::<usize>() <= 1 {
+            break;
+        }
+
+        // Where each range of candidates goes from one digit to the next.
+        let place = report.place(position);
+        let bounds: Vec<Vec<usize>> = candidates
+            .iter()
+            .map(|range| {
+                let numbers = &sorted[range.clone()];
+                let mut bounds = vec![range.start];
+
+                bounds.extend((1..radix).map(|digit| {
+                    range.start + numbers.partition_point(|n| report.digit(*n, place) < digit)
+                }));
+                bounds.push(range.end);
+
+                bounds
+            })
+            .collect();
+
+        let mut counts = vec![0; radix];
+        for bounds in &bounds {
+            for (digit, pair) in bounds.windows(2).enumerate() {
+                counts[digit] += pair[1] - pair[0];
+            }
+        }
+
+        let tie = || DiagnosticError::Tie { value, position };
+        let kept = ties.settle(extremes(&counts, most_common, false), tie)?;
+
+        candidates = bounds
+            .iter()
+            .flat_map(|bounds| {
+                kept.iter()
+                    .map(move |&digit| bounds[digit]..bounds[digit + 1])
+            })
+            .filter(|range| !range.is_empty())
+            .collect();
+
+        steps.push(Step {
+            counts,
+            kept,
+            remaining: candidates.iter().map(|range| range.len()).sum(),
+        });
+    }
+
+    // After the last position, each range holds copies of a single number.
+    match candidates.as_slice() {
+        [range] => Ok(Search {
+            steps,
+            rating: sorted[range.start],
+        }),
+        ranges => Err(DiagnosticError::Ambiguous {
+            value,
+            candidates: ranges.iter().map(|range| sorted[range.start]).collect(),
+        }),
+    }
+}
+
+/// The oxygen generator rating keeps the most common digits, and the CO2 scrubber rating the
+/// least common ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LifeSupport {
+    pub oxygen: Search,
+    pub co2: Search,
+}
+
+impl LifeSupport {
+    pub fn product(&self) -> Product {
+        Product::new(self.oxygen.rating, self.co2.rating)
+    }
+}
+
+pub fn life_support(
+    report: &Report,
+    oxygen_ties: TiePolicy,
+    co2_ties: TiePolicy,
+) -> Result<LifeSupport, DiagnosticError> {
+    let mut sorted = report.numbers.clone();
+    sorted.sort_unstable();
+
+    Ok(LifeSupport {
+        oxygen: search(
+            report,
+            &sorted,
+            true,
+            oxygen_ties,
+            "oxygen generator rating",
+        )?,
+        co2: search(report, &sorted, false, co2_ties, "CO2 scrubber rating")?,
+    })
+}
+
+/// Everything that goes into both answers, printable as a report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostics {
+    pub alphabet: Alphabet,
+    pub width: usize,
+    pub power: PowerConsumption,
+    pub life_support: LifeSupport,
+}
+
+impl Diagnostics {
+    /// The position column, then one column per digit.
+    fn write_counts(
+        &self,
+        f: &mut fmt::Formatter,
+        position: &dyn fmt::Display,
+        counts: &[usize],
+    ) -> fmt::Result {
+        write!(f, "{:>8}", position)?;
+
+        for count in counts {
+            write!(f, "  {:>6}", count)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_header(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Position")?;
+
+        for symbol in self.alphabet.symbols() {
+            write!(f, "  {:>6}", symbol)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_number(&self, f: &mut fmt::Formatter, name: &str, number: u128) -> fmt::Result {
+        let digits = self.alphabet.format(number, self.width);
+
+        writeln!(f, "{:<8} {} ({})", name, digits, number)
+    }
+
+    fn write_search(&self, f: &mut fmt::Formatter, name: &str, search: &Search) -> fmt::Result {
+        writeln!(f, "\n{}", name)?;
+        self.write_header(f)?;
+        writeln!(f, "  Kept  Left")?;
+
+        for (position, step) in search.steps.iter().enumerate() {
+            let kept: String = step
+                .kept
+                .iter()
+                .map(|&digit| self.alphabet.symbols()[digit])
+                .collect();
+
+            self.write_counts(f, &position, &step.counts)?;
+            writeln!(f, "  {:>4}  {:>4}", kept, step.remaining)?;
+        }
+
+        self.write_number(f, "rating", search.rating)
+    }
+}
+
+impl fmt::Display for Diagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write_header(f)?;
+        writeln!(f)?;
+
+        for (position, counts) in self.power.counts.iter().enumerate() {
+            self.write_counts(f, &position, counts)?;
+            writeln!(f)?;
+        }
+
+        self.write_number(f, "gamma", self.power.gamma)?;
+        self.write_number(f, "epsilon", self.power.epsilon)?;
+        self.write_search(f, "Oxygen generator rating", &self.life_support.oxygen)?;
+        self.write_search(f, "CO2 scrubber rating", &self.life_support.co2)
+    }
+}
+
+pub struct Day3 {
+    pub alphabet: Alphabet,
+    /// The digits gamma and epsilon get where several are equally common.
+    pub gamma_ties: TiePolicy,
+    pub oxygen_ties: TiePolicy,
+    pub co2_ties: TiePolicy,
+}
+
+impl Default for Day3 {
+    fn default() -> Self {
+        Self {
+            alphabet: Alphabet::binary(),
+            gamma_ties: TiePolicy::Error,
+            oxygen_ties: TiePolicy::PreferHighest,
+            co2_ties: TiePolicy::PreferLowest,
+        }
+    }
+}
+
+impl Day3 {
+    /// Works out both answers with the configured tie policies, keeping every step.
+    pub fn diagnose(&self, report: &Report) -> Result<Diagnostics, DiagnosticError> {
+        Ok(Diagnostics {
+            alphabet: report.alphabet.clone(),
+            width: report.width,
+            power: power_consumption(report, self.gamma_ties)?,
+            life_support: life_support(report, self.oxygen_ties, self.co2_ties)?,
+        })
+    }
+}
+
+impl Solution for Day3 {
+    type Input = Report;
+    type Answer1 = Product;
+    type Answer2 = Product;
+
+    const DAY: u8 = 3;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Report::parse(input, &self.alphabet)
+            .map_err(|invalid| ParseError::locate(Self::DAY, input, invalid))
+    }
+
+    fn part1(&self, input: &Self::Input) -> Product {
+        power_consumption(input, self.gamma_ties)
+            .unwrap_or_else(|error| panic!("Invalid input, {}.", error))
+            .product()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Product {
+        life_support(input, self.oxygen_ties, self.co2_ties)
+            .unwrap_or_else(|error| panic!("Invalid input, {}.", error))
+            .product()
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new(
+                "alphabet",
+                &self.alphabet,
+                "symbols of the digits, from lowest to highest",
+            ),
+            Param::new(
+                "gamma_ties",
+                self.gamma_ties,
+                "digit gamma gets on ties: highest, lowest or error",
+            ),
+            Param::new(
+                "oxygen_ties",
+                self.oxygen_ties,
+                "digit the oxygen rating keeps on ties: highest, lowest, error or keep_all",
+            ),
+            Param::new(
+                "co2_ties",
+                self.co2_ties,
+                "digit the CO2 rating keeps on ties: highest, lowest, error or keep_all",
+            ),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        let any = |_: &TiePolicy| true;
+        let expected = "highest, lowest, error or keep_all";
+
+        match name {
+            "alphabet" => {
+                self.alphabet =
+                    config::parse_value(name, value, "at least 2 different symbols", |_| true)?
+            }
+            "gamma_ties" => {
+                self.gamma_ties =
+                    config::parse_value(name, value, "highest, lowest or error", |policy| {
+                        *policy != TiePolicy::KeepAll
+                    })?
+            }
+            "oxygen_ties" => self.oxygen_ties = config::parse_value(name, value, expected, any)?,
+            "co2_ties" => self.co2_ties = config::parse_value(name, value, expected, any)?,
+            _ => return Err(ParamError::unknown(name)),
+        }
+
+        Ok(())
+    }
+
+    /// The per-position counts, gamma, epsilon and every step of both rating searches.
+    fn report(&self, _text: &str, input: &Self::Input) -> Option<String> {
+        let report = match self.diagnose(input) {
+            Ok(diagnostics) => diagnostics.to_string(),
+            Err(error) => format!("Invalid input, {}.", error),
+        };
+
+        Some(report)
+    }
+}
+
+/// Indices in the context of binary positions usually start at 0 on the right side:
+///
+///   0b0101010
+///           ^
+///           |
+///  LH Index = 0
+///
+/// However, this exercise counts indices as starting from the left hand side:
+///
+///   0b0101010
+///     ^
+///     |
+/// RH Index = 0
+///
+/// This function takes a LH index and converts into a RH index.
+fn right_hand_index(digits: usize, left_hand_index: usize) -> usize {
+    digits - left_hand_index - 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day3.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day3::default().parse(EXAMPLE).unwrap();
+
+        assert_eq!(input.width, 5);
+        assert_eq!(Day3::default().part1(&input), 198);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day3::default().parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day3::default().part2(&input), 230);
+    }
+
+    #[test]
+    fn ratings_keep_candidates_sharing_a_bit() {
+        // Every number starts with a 1, which mustn't leave the CO2 rating without candidates.
+        let input = Day3::default().parse("10\n11\n11").unwrap();
+
+        assert_eq!(Day3::default().part2(&input), 3 * 2);
+    }
+
+    #[test]
+    fn tie_policies() {
+        let input = Day3::default().parse("10\n01\n11\n00").unwrap();
+        let mut day3 = Day3::default();
+        let ratings = |day3: &Day3| {
+            life_support(&input, day3.oxygen_ties, day3.co2_ties)
+                .map(|ratings| (ratings.oxygen.rating, ratings.co2.rating))
+        };
+
+        assert_eq!(
+            power_consumption(&input, day3.gamma_ties),
+            Err(DiagnosticError::Tie {
+                value: "power consumption",
+                position: 0
+            })
+        );
+        assert_eq!(ratings(&day3), Ok((0b11, 0b00)));
+
+        day3.set_param("gamma_ties", "one").unwrap();
+        day3.set_param("oxygen_ties", "zero").unwrap();
+        day3.set_param("co2_ties", "one").unwrap();
+        assert_eq!(
+            power_consumption(&input, day3.gamma_ties).map(|power| (power.gamma, power.epsilon)),
+            Ok((0b11, 0b00))
+        );
+        assert_eq!(ratings(&day3), Ok((0b00, 0b11)));
+
+        assert!(day3.set_param("gamma_ties", "keep_all").is_err());
+        day3.set_param("oxygen_ties", "keep_all").unwrap();
+        assert_eq!(
+            ratings(&day3),
+            Err(DiagnosticError::Ambiguous {
+                value: "oxygen generator rating",
+                candidates: vec![0b00, 0b01, 0b10, 0b11]
+            })
+        );
+    }
+
+    #[test]
+    fn diagnostic_report() {
+        let input = Day3::default().parse(EXAMPLE).unwrap();
+        let diagnostics = Day3::default().diagnose(&input).unwrap();
+
+        assert_eq!(diagnostics.power.product(), 198);
+        assert_eq!(diagnostics.life_support.product(), 230);
+        assert_eq!(
+            diagnostics.life_support.oxygen.steps[0],
+            Step {
+                counts: vec![5, 7],
+                kept: vec![1],
+                remaining: 7
+            }
+        );
+
+        let report = diagnostics.to_string();
+        assert!(report.contains("\ngamma    10110 (22)\nepsilon  01001 (9)\n"));
+        assert!(report.ends_with("       2       1       1     0     1\nrating   01010 (10)\n"));
+
+        assert_eq!(
+            crate::Puzzle::report(&Day3::default(), EXAMPLE),
+            Ok(Some(report))
+        );
+    }
+
+    #[test]
+    fn any_alphabet() {
+        let mut day3 = Day3::default();
+        day3.set_param("alphabet", "xyz").unwrap();
+        let input = day3.parse("xz\nxz\nxy\nyz\nyz\nzy").unwrap();
+
+        // Nothing ends with an x, which makes it the least common last digit.
+        let power = power_consumption(&input, day3.gamma_ties).unwrap();
+        assert_eq!((power.gamma, power.epsilon), (2, 2 * 3));
+        assert_eq!(input.alphabet.format(power.epsilon, 2), "zx");
+        assert_eq!(day3.part2(&input), 2 * 7);
+
+        assert!(day3.set_param("alphabet", "xyx").is_err());
+    }
+
+    #[test]
+    fn radix() {
+        let hex = Alphabet::with_radix(16).unwrap();
+
+        assert_eq!(hex.to_string(), "0123456789abcdef");
+        assert_eq!(hex.format(0xc0de, 6), "00c0de");
+        assert_eq!(hex.max_width(), 32);
+        assert_eq!(Alphabet::with_radix(10).unwrap().max_width(), 38);
+        assert_eq!(Alphabet::binary().max_width(), 128);
+        assert_eq!(Alphabet::with_radix(37), None);
+    }
+
+    #[test]
+    fn widths_must_match() {
+        let error = Day3::default().parse("0101\n010\n").unwrap_err();
+
+        assert_eq!(
+            (error.line, error.kind),
+            (
+                2,
+                ParseErrorKind::WrongLength {
+                    expected: 4,
+                    found: 3
+                }
+            )
+        );
+        assert!(Day3::default().parse(&"1".repeat(129)).is_err());
+    }
+
+    #[test]
+    fn supports_128_bits() {
+        let input = Day3::default()
+            .parse(&format!("1{}\n0{}", "0".repeat(127), "1".repeat(127)))
+            .unwrap();
+
+        assert_eq!(input.width, 128);
+        assert_eq!(input.numbers, [1 << 127, u128::MAX >> 1]);
+
+        // The answers need more than 128 bits: 2^127 * (2^127 - 1) = 2^254 - 2^127.
+        let input = Day3::default()
+            .parse(&format!(
+                "1{}\n0{1}\n0{1}",
+                "0".repeat(127),
+                "1".repeat(127)
+            ))
+            .unwrap();
+        let answer =
+            "28948022309329048855892746252171976963147354982949671778132708698262398304256";
+        assert_eq!(Day3::default().part1(&input).to_string(), answer);
+        assert_eq!(Day3::default().part2(&input).to_string(), answer);
+
+        assert_eq!(
+            Product::new(u128::MAX, u128::MAX).to_string(),
+            "115792089237316195423570985008687907852589419931798687112530834793049593217025"
+        );
+        assert_eq!(
+            Product::new(1 << 64, 1 << 63).to_string(),
+            (1u128 << 127).to_string()
+        );
+    }
+}
@@ -0,0 +1,750 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Write},
+    str::FromStr,
+};
+
+use crate::{
+    config::{self, Param, ParamError},
+    error::{self, Invalid, ParseError, ParseErrorKind},
+    grid::Grid,
+    Solution,
+};
+
+/// A way of completing a board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WinRule {
+    Rows,
+    Columns,
+    /// Either diagonal, which only square boards have.
+    Diagonals,
+    /// All four corners.
+    Corners,
+    /// Every cell.
+    Blackout,
+}
+
+impl WinRule {
+    const ALL: [WinRule; 5] = [
+        WinRule::Rows,
+        WinRule::Columns,
+        WinRule::Diagonals,
+        WinRule::Corners,
+        WinRule::Blackout,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            WinRule::Rows => "rows",
+            WinRule::Columns => "columns",
+            WinRule::Diagonals => "diagonals",
+            WinRule::Corners => "corners",
+            WinRule::Blackout => "blackout",
+        }
+    }
+}
+
+/// The rules under which boards win, any one of them being enough.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WinRules(Vec<WinRule>);
+
+impl WinRules {
+    pub fn new(rules: &[WinRule]) -> Self {
+        Self(rules.to_vec())
+    }
+
+    pub fn contains(&self, rule: WinRule) -> bool {
+        self.0.contains(&rule)
+    }
+}
+
+impl Default for WinRules {
+    fn default() -> Self {
+        Self::new(&[WinRule::Rows, WinRule::Columns])
+    }
+}
+
+impl fmt::Display for WinRules {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let names = self.0.iter().map(|rule| rule.name()).collect::<Vec<_>>();
+
+        f.pad(&names.join(","))
+    }
+}
+
+impl FromStr for WinRules {
+    type Err = ();
+
+    /// Parses a comma separated list like `rows,columns`.
+    fn from_str(names: &str) -> Result<Self, ()> {
+        let rules = names
+            .split(',')
+            .map(|name| {
+                WinRule::ALL
+                    .iter()
+                    .copied()
+                    .find(|rule| rule.name() == name.trim())
+                    .ok_or(())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self(rules))
+    }
+}
+
+/// A cell of one of the boards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub board: usize,
+    pub row: usize,
+    pub column: usize,
+}
+
+/// Where each number is on all the boards, so that draws only touch the cells they mark.
+#[derive(Debug, Clone, Default)]
+pub struct NumberIndex(HashMap<u32, Vec<Cell>>);
+
+impl NumberIndex {
+    pub fn new(boards: &[Board]) -> Self {
+        let mut index = HashMap::<_, Vec<_>>::new();
+
+        for (board, cells) in boards.iter().enumerate() {
+            for ((column, row), (number, _)) in cells.cells.cells() {
+                index
+                    .entry(*number)
+                    .or_default()
+                    .push(Cell { board, row, column });
+            }
+        }
+
+        Self(index)
+    }
+
+    /// The cells holding `number`, by board and then row by row.
+    pub fn cells(&self, number: u32) -> &[Cell] {
+        self.0.get(&number).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// When a board won, and what its score was then.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Win {
+    /// Index of the winning number among the draws.
+    pub draw: usize,
+    pub number: u32,
+    pub score: u32,
+}
+
+/// How a whole game went, with each board playing until it wins.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timeline {
+    /// The win of every board, in the order of the input.
+    pub wins: Vec<Option<Win>>,
+}
+
+impl Timeline {
+    pub fn play(numbers_to_draw: &[u32], boards: &[Board], rules: &WinRules) -> Self {
+        let index = NumberIndex::new(boards);
+        let mut boards = boards.to_vec();
+        let mut wins = vec![None; boards.len()];
+
+        for (draw, &number) in numbers_to_draw.iter().enumerate() {
+            for cell in index.cells(number) {
+                if wins[cell.board].is_some() {
+                    continue;
+                }
+
+                let board = &mut boards[cell.board];
+                if let Some(score) = board.mark_at(cell.column, cell.row, rules) {
+                    wins[cell.board] = Some(Win {
+                        draw,
+                        number,
+                        score,
+                    });
+                }
+            }
+        }
+
+        Self { wins }
+    }
+
+    /// The boards that won along with their wins, in the order they won.
+    ///
+    /// Boards winning on the same draw are in the order of the input.
+    pub fn winners(&self) -> Vec<(usize, Win)> {
+        let mut winners = self
+            .wins
+            .iter()
+            .enumerate()
+            .filter_map(|(board, win)| Some((board, (*win)?)))
+            .collect::<Vec<_>>();
+        winners.sort_by_key(|(board, win)| (win.draw, *board));
+
+        winners
+    }
+
+    pub fn first(&self) -> Option<(usize, Win)> {
+        self.winners().first().copied()
+    }
+
+    /// The board that wins last, as long as every board eventually wins.
+    pub fn last(&self) -> Option<(usize, Win)> {
+        if self.wins.contains(&None) {
+            return None;
+        }
+
+        self.winners().last().copied()
+    }
+}
+
+impl fmt::Display for Timeline {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Board  Draw  Number  Score")?;
+
+        for (board, win) in self.wins.iter().enumerate() {
+            match win {
+                Some(win) => write!(
+                    f,
+                    "\n{:>5}  {:>4}  {:>6}  {:>5}",
+                    board, win.draw, win.number, win.score
+                )?,
+                None => write!(f, "\n{:>5}  never won", board)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn part1(numbers_to_draw: &[u32], boards: &[Board], rules: &WinRules) -> u32 {
+    let (_, win) = Timeline::play(numbers_to_draw, boards, rules)
+        .first()
+        .expect("Wrong input, no winners determined");
+
+    win.score
+}
+
+pub fn part2(numbers_to_draw: &[u32], boards: &[Board], rules: &WinRules) -> u32 {
+    let (_, win) = Timeline::play(numbers_to_draw, boards, rules)
+        .last()
+        .expect("Wrong input, last board doesn't actually win");
+
+    win.score
+}
+
+#[derive(Default)]
+pub struct Day4 {
+    pub win_rules: WinRules,
+}
+
+impl Solution for Day4 {
+    type Input = (Vec<u32>, Vec<Board>);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const DAY: u8 = 4;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input).map_err(|invalid| ParseError::locate(Self::DAY, input, invalid))
+    }
+
+    fn part1(&self, input: &Self::Input) -> u32 {
+        part1(&input.0, &input.1, &self.win_rules)
+    }
+
+    fn part2(&self, input: &Self::Input) -> u32 {
+        part2(&input.0, &input.1, &self.win_rules)
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![Param::new(
+            "win_rules",
+            &self.win_rules,
+            "ways to win: rows, columns, diagonals, corners or blackout",
+        )]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "win_rules" => {
+                self.win_rules = config::parse_value(
+                    name,
+                    value,
+                    "a comma separated list of rows, columns, diagonals, corners or blackout",
+                    |_| true,
+                )?
+            }
+            _ => return Err(ParamError::unknown(name)),
+        }
+
+        Ok(())
+    }
+
+    /// When each board wins, followed by everything `validate` finds wrong with the input.
+    fn report(&self, text: &str, input: &Self::Input) -> Option<String> {
+        let (numbers_to_draw, boards) = input;
+        let mut report = Timeline::play(numbers_to_draw, boards, &self.win_rules).to_string();
+
+        report.push_str("\n\nProblems");
+        let problems = validate(text);
+        if problems.is_empty() {
+            report.push_str("\nnone");
+        }
+        for problem in problems {
+            write!(report, "\n{}", ParseError::locate(Self::DAY, text, problem)).unwrap();
+        }
+        report.push('\n');
+
+        Some(report)
+    }
+}
+
+/// Each cell holds its number and whether it was marked.
+///
+/// Boards count the marks on each line, so that checking for a win doesn't go over cells.
+#[derive(Debug, Clone)]
+pub struct Board {
+    cells: Grid<(u32, bool)>,
+    row_hits: Vec<usize>,
+    column_hits: Vec<usize>,
+    /// Marks on the diagonal from the top left, and on the one from the top right.
+    diagonal_hits: [usize; 2],
+    marked: usize,
+    unmarked_sum: u32,
+}
+
+impl Board {
+    fn new(cells: Grid<(u32, bool)>) -> Self {
+        Self {
+            row_hits: vec![0; cells.height()],
+            column_hits: vec![0; cells.width()],
+            diagonal_hits: [0; 2],
+            marked: 0,
+            unmarked_sum: cells.iter().map(|(number, _)| number).sum(),
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    /// Marks every cell holding `number`, returning the score if that makes the board win.
+    ///
+    /// This goes over all the cells, `Timeline::play` uses a `NumberIndex` instead.
+    pub fn mark(&mut self, number: u32, rules: &WinRules) -> Option<u32> {
+        let positions = self
+            .cells
+            .cells()
+            .filter(|(_, (cell, _))| *cell == number)
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>();
+
+        positions
+            .into_iter()
+            .filter_map(|(x, y)| self.mark_at(x, y, rules))
+            .last()
+    }
+
+    /// Marks the cell at `(x, y)`, returning the score if that makes the board win.
+    pub fn mark_at(&mut self, x: usize, y: usize, rules: &WinRules) -> Option<u32> {
+        let (number, marked) = &mut self.cells[(x, y)];
+
+        if *marked {
+            return None;
+        }
+        *marked = true;
+        let number = *number;
+
+        self.row_hits[y] += 1;
+        self.column_hits[x] += 1;
+        if self.width() == self.height() {
+            if x == y {
+                self.diagonal_hits[0] += 1;
+            }
+            if x + y == self.width() - 1 {
+                self.diagonal_hits[1] += 1;
+            }
+        }
+        self.marked += 1;
+        self.unmarked_sum -= number;
+
+        Some(self.check_win(x, y, rules)? * number)
+    }
+
+    /// Checks the ways to win that go through the cell at `(x, y)`, which was just marked.
+    pub fn check_win(&self, x: usize, y: usize, rules: &WinRules) -> Option<u32> {
+        let (width, height) = (self.width(), self.height());
+        let corners = [
+            (0, 0),
+            (width - 1, 0),
+            (0, height - 1),
+            (width - 1, height - 1),
+        ];
+
+        let won = rules.0.iter().any(|rule| match rule {
+            WinRule::Rows => self.row_hits[y] == width,
+            WinRule::Columns => self.column_hits[x] == height,
+            WinRule::Diagonals if width == height => {
+                (x == y && self.diagonal_hits[0] == width)
+                    || (x + y == width - 1 && self.diagonal_hits[1] == width)
+            }
+            WinRule::Diagonals => false,
+            WinRule::Corners => {
+                corners.contains(&(x, y)) && corners.iter().all(|&c| self.cells[c].1)
+            }
+            WinRule::Blackout => self.marked == width * height,
+        });
+
+        if won {
+            Some(self.sum_unmarked())
+        } else {
+            None
+        }
+    }
+
+    pub fn sum_unmarked(&self) -> u32 {
+        self.unmarked_sum
+    }
+
+    /// Parses a board of any size, as wide as its first row. Numbers can't repeat.
+    pub fn parse(input: &str) -> Result<Self, Invalid<'_>> {
+        let rows = board_rows(input)?;
+        let mut grid = Grid::new(rows[0].len(), rows.len(), (0, false));
+        let mut numbers = HashSet::new();
+
+        for (y, row) in rows.into_iter().enumerate() {
+            for (x, n) in row.into_iter().enumerate() {
+                let number = error::parse_number(n)?;
+
+                if !numbers.insert(number) {
+                    return Err(Invalid::new(n, ParseErrorKind::Duplicate));
+                }
+                grid[(x, y)].0 = number;
+            }
+        }
+
+        Ok(Board::new(grid))
+    }
+}
+
+/// Splits a board into the text of its cells, checking that every row is as wide as the first.
+fn board_rows(input: &str) -> Result<Vec<Vec<&str>>, Invalid<'_>> {
+    let rows = input
+        .split('\n')
+        .map(|row| (row, row.split_whitespace().collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+    let width = rows[0].1.len();
+
+    if width == 0 {
+        return Err(Invalid::new(input, ParseErrorKind::Empty));
+    }
+
+    rows.into_iter()
+        .map(|(row, cells)| {
+            if cells.len() == width {
+                Ok(cells)
+            } else {
+                Err(Invalid::new(
+                    row,
+                    ParseErrorKind::WrongLength {
+                        expected: width,
+                        found: cells.len(),
+                    },
+                ))
+            }
+        })
+        .collect()
+}
+
+/// Parses the draws and the boards, rejecting numbers that are drawn twice or repeat within a
+/// board. Numbers that are never drawn only keep a board from winning, so they're left to
+/// `validate`.
+fn parse(input: &str) -> Result<(Vec<u32>, Vec<Board>), Invalid<'_>> {
+    let mut it = input.split("\n\n");
+    let mut drawn = HashSet::new();
+
+    let numbers_to_draw = it
+        .next()
+        .unwrap_or_default()
+        .split(',')
+        .map(|draw| match error::parse_number(draw)? {
+            number if drawn.insert(number) => Ok(number),
+            _ => Err(Invalid::new(draw, ParseErrorKind::Duplicate)),
+        })
+        .collect::<Result<_, _>>()?;
+
+    let boards = it.map(Board::parse).collect::<Result<_, _>>()?;
+
+    Ok((numbers_to_draw, boards))
+}
+
+/// Finds what's wrong with a bingo input, in the order it appears: draws that repeat, boards
+/// that aren't rectangular, numbers repeated within a board and numbers that are never drawn.
+///
+/// Only the first shape problem of a board is reported, and the rest of that board is skipped.
+/// Numbers that can't be parsed are left to `parse`.
+pub fn validate(input: &str) -> Vec<Invalid<'_>> {
+    let mut problems = vec![];
+    let mut sections = input.split("\n\n");
+    let mut drawn = HashSet::new();
+
+    for draw in sections.next().unwrap_or_default().split(',') {
+        if let Ok(number) = draw.parse::<u32>() {
+            if !drawn.insert(number) {
+                problems.push(Invalid::new(draw, ParseErrorKind::Duplicate));
+            }
+        }
+    }
+
+    for board in sections {
+        let rows = match board_rows(board) {
+            Ok(rows) => rows,
+            Err(problem) => {
+                problems.push(problem);
+                continue;
+            }
+        };
+        let mut numbers = HashSet::new();
+
+        for cells in rows {
+            for cell in cells {
+                let number = match cell.parse::<u32>() {
+                    Ok(number) => number,
+                    Err(_) => continue,
+                };
+
+                if !numbers.insert(number) {
+                    problems.push(Invalid::new(cell, ParseErrorKind::Duplicate));
+                } else if !drawn.contains(&number) {
+                    problems.push(Invalid::new(
+                        cell,
+                        ParseErrorKind::Expected("a number that gets drawn"),
+                    ));
+                }
+            }
+        }
+    }
+
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day4.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day4::default().parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day4::default().part1(&input), 4512);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day4::default().parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day4::default().part2(&input), 1924);
+    }
+
+    #[test]
+    fn timeline() {
+        let (numbers, boards) = Day4::default().parse(EXAMPLE).unwrap();
+        let timeline = Timeline::play(&numbers, &boards, &WinRules::default());
+
+        assert_eq!(
+            timeline.winners(),
+            [
+                (
+                    2,
+                    Win {
+                        draw: 11,
+                        number: 24,
+                        score: 4512
+                    }
+                ),
+                (
+                    0,
+                    Win {
+                        draw: 13,
+                        number: 16,
+                        score: 2192
+                    }
+                ),
+                (
+                    1,
+                    Win {
+                        draw: 14,
+                        number: 13,
+                        score: 1924
+                    }
+                )
+            ]
+        );
+        assert!(timeline
+            .to_string()
+            .starts_with("Board  Draw  Number  Score\n    0    13      16   2192\n"));
+
+        let timeline = Timeline::play(&numbers[..12], &boards, &WinRules::default());
+        assert_eq!(timeline.first().map(|(board, _)| board), Some(2));
+        assert_eq!(timeline.last(), None);
+        assert!(timeline.to_string().contains("\n    1  never won\n"));
+    }
+
+    #[test]
+    fn number_index() {
+        let boards = [
+            Board::parse("1 2\n3 4").unwrap(),
+            Board::parse("4 5 1").unwrap(),
+        ];
+        let index = NumberIndex::new(&boards);
+
+        assert_eq!(
+            index.cells(1),
+            [
+                Cell {
+                    board: 0,
+                    row: 0,
+                    column: 0
+                },
+                Cell {
+                    board: 1,
+                    row: 0,
+                    column: 2
+                }
+            ]
+        );
+        assert_eq!(index.cells(6), []);
+    }
+
+    #[test]
+    fn validation() {
+        assert_eq!(validate(EXAMPLE), []);
+
+        let input = "1,2,1,3\n\n1 2\n3\n\n3 3 4";
+        let kinds = validate(input)
+            .into_iter()
+            .map(|invalid| (invalid.text, invalid.kind))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            kinds,
+            [
+                ("1", ParseErrorKind::Duplicate),
+                (
+                    "3",
+                    ParseErrorKind::WrongLength {
+                        expected: 2,
+                        found: 1
+                    }
+                ),
+                ("3", ParseErrorKind::Duplicate),
+                ("4", ParseErrorKind::Expected("a number that gets drawn")),
+            ]
+        );
+
+        let error = Day4::default().parse("1,2,3,2\n\n1 2\n3 1").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.kind),
+            (1, 7, ParseErrorKind::Duplicate)
+        );
+    }
+
+    #[test]
+    fn undrawn_numbers_still_parse() {
+        let input = "1,2,3\n\n1 2\n3 4\n\n1 2\n3 9";
+        let (numbers, boards) = Day4::default().parse(input).unwrap();
+        let timeline = Timeline::play(&numbers, &boards, &WinRules::default());
+
+        assert_eq!(
+            timeline.first(),
+            Some((
+                0,
+                Win {
+                    draw: 1,
+                    number: 2,
+                    score: 14
+                }
+            ))
+        );
+
+        let undrawn = validate(input)
+            .into_iter()
+            .map(|invalid| (invalid.text, invalid.kind))
+            .collect::<Vec<_>>();
+        let expected = ParseErrorKind::Expected("a number that gets drawn");
+        assert_eq!(undrawn, [("4", expected.clone()), ("9", expected)]);
+
+        let report = Solution::report(&Day4::default(), input, &(numbers, boards)).unwrap();
+        assert!(report.starts_with("Board  Draw  Number  Score\n    0     1       2     14\n"));
+        assert!(report.ends_with(
+            "\n\nProblems\nDay 4 input, line 4, column 3: expected a number that gets drawn, \
+             found \"4\"\nDay 4 input, line 7, column 3: expected a number that gets drawn, \
+             found \"9\"\n"
+        ));
+
+        let error = Day4::default().parse("1,2\n\n1 2\n2 1").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.kind),
+            (4, 1, ParseErrorKind::Duplicate)
+        );
+    }
+
+    #[test]
+    fn board_shapes_and_win_rules() {
+        let board = Board::parse("1 2 3\n4 5 6\n7 8 9").unwrap();
+        let wins = |rules: &str, draws: &[u32]| {
+            let rules = rules.parse().unwrap();
+            let mut board = board.clone();
+
+            draws.iter().find_map(|&number| board.mark(number, &rules))
+        };
+
+        assert_eq!(wins("rows,columns", &[1, 5, 9]), None);
+        assert_eq!(
+            wins("diagonals", &[1, 5, 9]),
+            Some((2 + 3 + 4 + 6 + 7 + 8) * 9)
+        );
+        assert_eq!(
+            wins("diagonals", &[3, 5, 7]),
+            Some((1 + 2 + 4 + 6 + 8 + 9) * 7)
+        );
+        assert_eq!(
+            wins("corners", &[1, 3, 9, 7]),
+            Some((2 + 4 + 5 + 6 + 8) * 7)
+        );
+        assert_eq!(wins("rows,blackout", &[1, 2, 4, 5, 7, 8]), None);
+        assert_eq!(wins("blackout", &[1, 2, 3, 4, 5, 6, 7, 8, 9]), Some(0));
+
+        // Non-square boards have no diagonals.
+        let mut wide = Board::parse("1 2 3\n4 5 6").unwrap();
+        let rules = WinRules::new(&[WinRule::Diagonals, WinRule::Columns]);
+        assert_eq!(wide.mark(1, &rules), None);
+        assert_eq!(wide.mark(5, &rules), None);
+        assert_eq!(wide.mark(4, &rules), Some((2 + 3 + 6) * 4));
+
+        assert!("rows,zigzags".parse::<WinRules>().is_err());
+    }
+
+    #[test]
+    fn boards_are_as_big_as_their_rows() {
+        let error = Board::parse("1 2 3\n4 5").unwrap_err();
+
+        assert_eq!(
+            error,
+            Invalid::new(
+                "4 5",
+                ParseErrorKind::WrongLength {
+                    expected: 3,
+                    found: 2
+                }
+            )
+        );
+    }
+}
@@ -0,0 +1,229 @@
+use std::{cmp::Ordering, vec};
+
+use crate::{
+    config::{self, Param, ParamError},
+    error::{self, Invalid, ParseError, ParseErrorKind},
+    grid::Grid,
+    Solution,
+};
+
+/// Counts the points covered by at least `overlap_threshold` lines.
+fn solve(lines: &[Line], include_diagonals: bool, overlap_threshold: i32) -> i32 {
+    let width = lines
+        .iter()
+        .map(|line| line.start.x.max(line.end.x))
+        .max()
+        .unwrap_or(0) as usize
+        + 1;
+    let height = lines
+        .iter()
+        .map(|line| line.start.y.max(line.end.y))
+        .max()
+        .unwrap_or(0) as usize
+        + 1;
+
+    let mut world = Grid::new(width, height, 0);
+
+    for line in lines {
+        for point in line.iter(include_diagonals) {
+            world[(point.x as usize, point.y as usize)] += 1;
+        }
+    }
+
+    world
+        .iter()
+        .filter(|cell| **cell >= overlap_threshold)
+        .count() as i32
+}
+
+pub fn part1(lines: &[Line], overlap_threshold: i32) -> i32 {
+    solve(lines, false, overlap_threshold)
+}
+
+pub fn part2(lines: &[Line], overlap_threshold: i32) -> i32 {
+    solve(lines, true, overlap_threshold)
+}
+
+pub struct Day5 {
+    /// How many lines have to cross a point for it to count.
+    pub overlap_threshold: i32,
+}
+
+impl Default for Day5 {
+    fn default() -> Self {
+        Self {
+            overlap_threshold: 2,
+        }
+    }
+}
+
+impl Solution for Day5 {
+    type Input = Vec<Line>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    const DAY: u8 = 5;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .split('\n')
+            .map(Line::parse)
+            .collect::<Result<_, _>>()
+            .map_err(|invalid| ParseError::locate(Self::DAY, input, invalid))
+    }
+
+    fn part1(&self, input: &Self::Input) -> i32 {
+        part1(input, self.overlap_threshold)
+    }
+
+    fn part2(&self, input: &Self::Input) -> i32 {
+        part2(input, self.overlap_threshold)
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![Param::new(
+            "overlap_threshold",
+            self.overlap_threshold,
+            "lines that have to cross a point for it to count",
+        )]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "overlap_threshold" => {
+                self.overlap_threshold =
+                    config::parse_value(name, value, "a positive number", |n| *n > 0)?
+            }
+            _ => return Err(ParamError::unknown(name)),
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    /// Parses a string like:
+    ///  "0,9"
+    pub fn parse(input: &str) -> Result<Self, Invalid<'_>> {
+        let (str_x, str_y) = input
+            .split_once(',')
+            .ok_or_else(|| Invalid::new(input, ParseErrorKind::Expected("`<x>,<y>`")))?;
+
+        let x = Self::parse_coordinate(str_x)?;
+        let y = Self::parse_coordinate(str_y)?;
+
+        Ok(Self { x, y })
+    }
+
+    fn parse_coordinate(input: &str) -> Result<i32, Invalid<'_>> {
+        let coordinate = error::parse_number(input)?;
+
+        if coordinate < 0 {
+            return Err(Invalid::new(
+                input,
+                ParseErrorKind::Expected("a non-negative coordinate"),
+            ));
+        }
+
+        Ok(coordinate)
+    }
+
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match self.x.cmp(&other.x) {
+            Ordering::Equal => self.y.cmp(&other.y),
+            ordering => ordering,
+        }
+    }
+
+    pub fn sort2(a: Point, b: Point) -> (Point, Point) {
+        match a.cmp(&b) {
+            Ordering::Less => (a, b),
+            Ordering::Equal => (a, b),
+            Ordering::Greater => (b, a),
+        }
+    }
+}
+
+/// Lines assume sorted points.
+#[derive(Debug)]
+pub struct Line {
+    /// "Top-left" point
+    start: Point,
+    /// "Bottom-right" point
+    end: Point,
+}
+
+impl Line {
+    /// Parses a string like:
+    ///  "0,9 -> 5,9"
+    pub fn parse(input: &str) -> Result<Self, Invalid<'_>> {
+        let (str_p1, str_p2) = input
+            .split_once(" -> ")
+            .ok_or_else(|| Invalid::new(input, ParseErrorKind::Expected("`<x>,<y> -> <x>,<y>`")))?;
+
+        let p1 = Point::parse(str_p1)?;
+        let p2 = Point::parse(str_p2)?;
+
+        let (start, end) = Point::sort2(p1, p2);
+
+        Ok(Self { start, end })
+    }
+
+    pub fn iter(&self, include_diagonals: bool) -> impl Iterator<Item = Point> {
+        let is_vertical = self.start.x == self.end.x;
+        let is_horizontal = self.start.y == self.end.y;
+        let mut vec = vec![];
+
+        // We do the two checks to ignore diagonal lines (part1).
+        if is_vertical {
+            for y in self.start.y..=self.end.y {
+                vec.push(Point { x: self.start.x, y })
+            }
+        } else if is_horizontal {
+            for x in self.start.x..=self.end.x {
+                vec.push(Point { x, y: self.start.y })
+            }
+        } else if include_diagonals {
+            // Diagonal (45 degree) case
+            let dx = self.end.x - self.start.x;
+            let dy = self.end.y - self.start.y;
+            let slope = dy / dx;
+
+            for x in self.start.x..=self.end.x {
+                vec.push(Point {
+                    x,
+                    y: self.start.y + slope * (x - self.start.x),
+                })
+            }
+        }
+
+        vec.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day5.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day5::default().parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day5::default().part1(&input), 5);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day5::default().parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day5::default().part2(&input), 12);
+    }
+}
@@ -0,0 +1,174 @@
+use std::fmt;
+
+use crate::{
+    config::{self, Param, ParamError},
+    error::{self, Invalid, ParseError, ParseErrorKind},
+    Solution,
+};
+
+fn parse_timer(input: &str) -> Result<usize, Invalid<'_>> {
+    let timer = error::parse_number(input)?;
+
+    if timer > 8 {
+        return Err(Invalid::new(
+            input,
+            ParseErrorKind::Expected("a timer between 0 and 8"),
+        ));
+    }
+
+    Ok(timer)
+}
+
+/// How many fish there are after a simulation, unless they got too many to count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Population(pub Option<usize>);
+
+impl PartialEq<usize> for Population {
+    fn eq(&self, other: &usize) -> bool {
+        self.0 == Some(*other)
+    }
+}
+
+impl fmt::Display for Population {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Some(count) => write!(f, "{}", count),
+            None => write!(f, "overflow, more than {} fish", usize::MAX),
+        }
+    }
+}
+
+pub fn solve(numbers: &[usize], days_to_run: usize) -> Population {
+    let mut fish_count = [0usize; 9];
+
+    for n in numbers {
+        fish_count[*n] += 1;
+    }
+
+    for _ in 0..days_to_run {
+        // Simulating 1 day
+        let mut zero_fish = 0;
+        for i in 0..9 {
+            if i == 0 {
+                zero_fish = fish_count[0];
+            } else {
+                fish_count[i - 1] = fish_count[i]
+            }
+        }
+        fish_count[6] = match fish_count[6].checked_add(zero_fish) {
+            Some(count) => count,
+            None => return Population(None),
+        };
+        fish_count[8] = zero_fish;
+    }
+
+    let total = fish_count
+        .iter()
+        .try_fold(0usize, |total, count| total.checked_add(*count));
+
+    Population(total)
+}
+
+pub struct Day6 {
+    /// Days simulated by each part.
+    pub part1_days: usize,
+    pub part2_days: usize,
+}
+
+impl Default for Day6 {
+    fn default() -> Self {
+        Self {
+            part1_days: 80,
+            part2_days: 256,
+        }
+    }
+}
+
+impl Solution for Day6 {
+    type Input = Vec<usize>;
+    type Answer1 = Population;
+    type Answer2 = Population;
+
+    const DAY: u8 = 6;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .split(',')
+            .map(parse_timer)
+            .collect::<Result<_, _>>()
+            .map_err(|invalid| ParseError::locate(Self::DAY, input, invalid))
+    }
+
+    fn part1(&self, input: &Self::Input) -> Population {
+        solve(input, self.part1_days)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Population {
+        solve(input, self.part2_days)
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("part1_days", self.part1_days, "days simulated by part 1"),
+            Param::new("part2_days", self.part2_days, "days simulated by part 2"),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        let days = || config::parse_value(name, value, "a number of days", |_| true);
+
+        match name {
+            "part1_days" => self.part1_days = days()?,
+            "part2_days" => self.part2_days = days()?,
+            _ => return Err(ParamError::unknown(name)),
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day6.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day6::default().parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day6::default().part1(&input), 5934);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day6::default().parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day6::default().part2(&input), 26984457539);
+    }
+
+    #[test]
+    fn days_can_be_configured() {
+        let mut day = Day6::default();
+        day.set_param("part1_days", "18").unwrap();
+        let input = day.parse(EXAMPLE).unwrap();
+
+        assert_eq!(day.part1(&input), 26);
+        assert!(day.set_param("part1_days", "-1").is_err());
+        assert!(day.set_param("days", "18").is_err());
+    }
+
+    #[test]
+    fn overflow_is_reported() {
+        let mut day = Day6::default();
+        day.set_param("part2_days", "500").unwrap();
+        let input = day.parse(EXAMPLE).unwrap();
+
+        assert_eq!(day.part2(&input), Population(None));
+        assert_eq!(
+            day.part2(&input).to_string(),
+            format!("overflow, more than {} fish", usize::MAX)
+        );
+        assert_eq!(solve(&[0], 0).to_string(), "1");
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    error::{self, ParseError},
+    Solution,
+};
+
+fn solve(numbers: &[i32], cost_fn: fn(i32) -> i32) -> i32 {
+    let min = *numbers.iter().min().unwrap();
+    let max = *numbers.iter().max().unwrap();
+
+    (min..=max)
+        .map(|candidate_y| {
+            let res: i32 = numbers
+                .iter()
+                .map(|n| cost_fn((n - candidate_y).abs()))
+                .sum();
+
+            res
+        })
+        .min()
+        .unwrap()
+}
+
+pub fn part1(numbers: &[i32]) -> i32 {
+    let cost_fn = |steps| steps;
+
+    solve(numbers, cost_fn)
+}
+
+pub fn part2(numbers: &[i32]) -> i32 {
+    // 1 + 2 + ... + n = n*(n + 1)/2
+    let cost_fn = |steps| steps * (steps + 1) / 2;
+
+    solve(numbers, cost_fn)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    const DAY: u8 = 7;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .split(',')
+            .map(error::parse_number)
+            .collect::<Result<_, _>>()
+            .map_err(|invalid| ParseError::locate(Self::DAY, input, invalid))
+    }
+
+    fn part1(&self, input: &Self::Input) -> i32 {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> i32 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day7.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day7.parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day7.part1(&input), 37);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day7.parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day7.part2(&input), 168);
+    }
+}
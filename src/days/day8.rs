@@ -0,0 +1,407 @@
+use std::collections::HashMap;
+
+use crate::{
+    error::{Invalid, ParseError, ParseErrorKind},
+    Solution,
+};
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub struct Signal {
+    id: u32,
+}
+
+impl Signal {
+    pub fn parse(signal: &str) -> Result<Self, Invalid<'_>> {
+        let mut res = 0;
+        for (index, char) in signal.char_indices() {
+            if !('a'..='g').contains(&char) {
+                let text = &signal[index..index + char.len_utf8()];
+
+                return Err(Invalid::new(text, ParseErrorKind::UnexpectedChar));
+            }
+
+            res |= 1 << (char as u8 - b'a')
+        }
+
+        Ok(Self { id: res })
+    }
+
+    pub fn count(&self) -> u32 {
+        self.id.count_ones()
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        Self {
+            id: self.id & (!other.id),
+        }
+    }
+}
+
+/// The ten unique signal patterns seen on a display, and the four digits it shows.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub patterns: Vec<Signal>,
+    pub output: Vec<Signal>,
+}
+
+impl Entry {
+    /// Parses a line like:
+    ///  "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe"
+    pub fn parse(line: &str) -> Result<Self, Invalid<'_>> {
+        let (patterns_str, output_str) = line.split_once(" | ").ok_or_else(|| {
+            Invalid::new(line, ParseErrorKind::Expected("`<patterns> | <output>`"))
+        })?;
+
+        let patterns = patterns_str
+            .split_ascii_whitespace()
+            .map(Signal::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        let output_strs = output_str.split_ascii_whitespace().collect::<Vec<_>>();
+        let output = output_strs
+            .iter()
+            .copied()
+            .map(Signal::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if patterns.len() != 10 {
+            return Err(Invalid::new(
+                patterns_str,
+                ParseErrorKind::WrongLength {
+                    expected: 10,
+                    found: patterns.len(),
+                },
+            ));
+        }
+
+        if !is_display(&patterns) {
+            return Err(Invalid::new(
+                patterns_str,
+                ParseErrorKind::Expected("the ten digits of a display"),
+            ));
+        }
+
+        if let Some(index) = output.iter().position(|signal| !patterns.contains(signal)) {
+            return Err(Invalid::new(
+                output_strs[index],
+                ParseErrorKind::Expected("one of the patterns"),
+            ));
+        }
+
+        Ok(Self { patterns, output })
+    }
+}
+
+/// The segments lit for each digit, with bit 0 for `a` up to bit 6 for `g`.
+const DIGITS: [u32; 10] = [
+    0b1110111, 0b0100100, 0b1011101, 0b1101101, 0b0101110, 0b1101011, 0b1111011, 0b0100101,
+    0b1111111, 0b1101111,
+];
+
+/// The sizes of the digits a wire is part of, which is different for every segment.
+fn wire_signature(wire: u32, digits: &[u32]) -> Vec<u32> {
+    let mut sizes = digits
+        .iter()
+        .filter(|digit| *digit & 1 << wire != 0)
+        .map(|digit| digit.count_ones())
+        .collect::<Vec<_>>();
+    sizes.sort_unstable();
+
+    sizes
+}
+
+/// Whether the patterns are the ten digits of a display, whatever its wiring.
+///
+/// Each wire is matched to the segment with the same signature, and the patterns have to turn
+/// into the ten digits once rewired.
+fn is_display(patterns: &[Signal]) -> bool {
+    let ids = patterns.iter().map(|signal| signal.id).collect::<Vec<_>>();
+    let mut rewired = vec![0; ids.len()];
+
+    for wire in 0..7 {
+        let signature = wire_signature(wire, &ids);
+        let segment = match (0..7).find(|segment| wire_signature(*segment, &DIGITS) == signature) {
+            Some(segment) => segment,
+            None => return false,
+        };
+
+        for (rewired, id) in rewired.iter_mut().zip(&ids) {
+            if id & 1 << wire != 0 {
+                *rewired |= 1 << segment;
+            }
+        }
+    }
+
+    let mut digits = DIGITS;
+    digits.sort_unstable();
+    rewired.sort_unstable();
+
+    rewired == digits
+}
+
+#[derive(Debug)]
+struct SignalDecoder {
+    unknown_digits: Vec<Signal>,
+    known_digits: HashMap<u32, Signal>,
+    reverse_lookup: HashMap<Signal, u32>,
+}
+
+impl SignalDecoder {
+    pub fn new(patterns: &[Signal]) -> Self {
+        let mut decoder = Self {
+            unknown_digits: patterns.to_vec(),
+            known_digits: HashMap::new(),
+            reverse_lookup: HashMap::new(),
+        };
+
+        decoder.init();
+
+        decoder
+    }
+
+    fn init(&mut self) {
+        self.find_1_4_7_8();
+        self.find_3();
+        self.find_9();
+        self.find_2();
+        self.find_5();
+        self.find_0();
+        self.find_6();
+    }
+
+    // These have unique segment counts
+    fn find_1_4_7_8(&mut self) {
+        let mut i = 0;
+        let len_to_number = [(2, 1), (4, 4), (3, 7), (7, 8)];
+
+        while i < self.unknown_digits.len() {
+            let mut inc = true;
+            for (len, num) in &len_to_number {
+                if self.unknown_digits[i].count() == *len {
+                    let signal = self.unknown_digits.remove(i);
+                    self.known_digits.insert(*num, signal);
+                    self.reverse_lookup.insert(signal, *num);
+                    inc = false;
+                    break;
+                }
+            }
+            i += inc as usize;
+        }
+    }
+
+    // (unknown_digit - 7).count() == 2
+    fn find_3(&mut self) {
+        let mut i = 0;
+        while i < self.unknown_digits.len() {
+            if self.unknown_digits[i]
+                .difference(&self.known_digits[&7])
+                .count()
+                == 2
+            {
+                let signal = self.unknown_digits.remove(i);
+                self.known_digits.insert(3, signal);
+                self.reverse_lookup.insert(signal, 3);
+
+                return;
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    // (4 - unknown_digit).count() == 0
+    fn find_9(&mut self) {
+        let four: Signal = self.known_digits[&4];
+
+        let mut i = 0;
+        while i < self.unknown_digits.len() {
+            if four.difference(&self.unknown_digits[i]).count() == 0 {
+                let signal = self.unknown_digits.remove(i);
+                self.known_digits.insert(9, signal);
+                self.reverse_lookup.insert(signal, 9);
+
+                return;
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    // (9 - unknown_digit).count() == 2
+    fn find_2(&mut self) {
+        let nine: Signal = self.known_digits[&9];
+
+        let mut i = 0;
+        while i < self.unknown_digits.len() {
+            if nine.difference(&self.unknown_digits[i]).count() == 2 {
+                let signal = self.unknown_digits.remove(i);
+                self.known_digits.insert(2, signal);
+                self.reverse_lookup.insert(signal, 2);
+
+                return;
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    // (2 - unknown_digit).count() == 2
+    fn find_5(&mut self) {
+        let two: Signal = self.known_digits[&2];
+
+        let mut i = 0;
+        while i < self.unknown_digits.len() {
+            if two.difference(&self.unknown_digits[i]).count() == 2 {
+                let signal = self.unknown_digits.remove(i);
+                self.known_digits.insert(5, signal);
+                self.reverse_lookup.insert(signal, 5);
+
+                return;
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    // (unknown_digit - 5).count() == 2
+    fn find_0(&mut self) {
+        let five: Signal = self.known_digits[&5];
+
+        let mut i = 0;
+        while i < self.unknown_digits.len() {
+            if self.unknown_digits[i].difference(&five).count() == 2 {
+                let signal = self.unknown_digits.remove(i);
+                self.known_digits.insert(0, signal);
+                self.reverse_lookup.insert(signal, 0);
+
+                return;
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    // Last one missing
+    fn find_6(&mut self) {
+        let signal = self.unknown_digits.remove(0);
+        self.known_digits.insert(6, signal);
+        self.reverse_lookup.insert(signal, 6);
+    }
+
+    pub fn decode(&self, signal: &Signal) -> u32 {
+        self.reverse_lookup[signal]
+    }
+}
+
+fn has_unique_digits(signal: &Signal) -> bool {
+    let len = signal.count();
+
+    let is_1 = len == 2;
+    let is_4 = len == 4;
+    let is_7 = len == 3;
+    let is_8 = len == 7;
+
+    is_1 || is_4 || is_7 || is_8
+}
+
+pub fn part1(entries: &[Entry]) -> usize {
+    entries
+        .iter()
+        .flat_map(|entry| entry.output.iter())
+        .filter(|signal| has_unique_digits(signal))
+        .count()
+}
+
+pub fn part2(entries: &[Entry]) -> u32 {
+    let mut total_res = 0;
+    for entry in entries {
+        let signal_decoder = SignalDecoder::new(&entry.patterns);
+
+        let mut res = 0;
+        for signal in &entry.output {
+            let decoded = signal_decoder.decode(signal);
+            res = res * 10 + decoded;
+        }
+        total_res += res
+    }
+    total_res
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<Entry>;
+    type Answer1 = usize;
+    type Answer2 = u32;
+
+    const DAY: u8 = 8;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(Entry::parse)
+            .collect::<Result<_, _>>()
+            .map_err(|invalid| ParseError::locate(Self::DAY, input, invalid))
+    }
+
+    fn part1(&self, input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> u32 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day8.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day8.parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day8.part1(&input), 26);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day8.parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day8.part2(&input), 61229);
+    }
+
+    #[test]
+    fn undecodable_entries() {
+        let error = Day8
+            .parse("ab ab ab ab ab ab ab ab ab ab | ab")
+            .unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.kind),
+            (
+                1,
+                1,
+                ParseErrorKind::Expected("the ten digits of a display")
+            )
+        );
+
+        // Every pattern has the right size, but `cf` and `ac` can't be wired together.
+        let error = Day8
+            .parse("abcefg cf acdeg acdfg bcdf abdfg abdefg ac abcdefg abcdfg | cf")
+            .unwrap_err();
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expected("the ten digits of a display")
+        );
+
+        let line =
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdb ab";
+        let error = Day8.parse(line).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.kind),
+            (1, 74, ParseErrorKind::Expected("one of the patterns"))
+        );
+        assert!(Day8.parse(&line.replace("cdb", "cdfbe")).is_ok());
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{error::ParseError, grid::Grid, Solution};
+
+fn find_minimums(grid: &Grid<u32>) -> Vec<(usize, usize)> {
+    grid.cells()
+        .filter(|((x, y), height)| {
+            grid.neighbors4(*x, *y)
+                .all(|neighbor| grid[neighbor] > **height)
+        })
+        .map(|(position, _)| position)
+        .collect()
+}
+
+fn basin(grid: &Grid<u32>, x: usize, y: usize) -> Vec<&u32> {
+    let mut res = vec![];
+    let mut visited = Grid::new(grid.width(), grid.height(), false);
+    dfs(grid, x, y, &mut visited, &mut res);
+
+    res
+}
+
+fn dfs<'a>(
+    grid: &'a Grid<u32>,
+    x: usize,
+    y: usize,
+    visited: &mut Grid<bool>,
+    res: &mut Vec<&'a u32>,
+) {
+    let current = &grid[(x, y)];
+
+    visited[(x, y)] = true;
+    res.push(current);
+
+    let real_neighbors = grid
+        .neighbors4(x, y)
+        .filter(|neighbor| grid[*neighbor] < 9 && grid[*neighbor] > *current);
+
+    for (n_x, n_y) in real_neighbors {
+        if !visited[(n_x, n_y)] {
+            dfs(grid, n_x, n_y, visited, res);
+        }
+    }
+}
+
+pub fn part1(grid: &Grid<u32>) -> u32 {
+    find_minimums(grid)
+        .iter()
+        .map(|position| grid[*position] + 1)
+        .sum()
+}
+
+pub fn part2(grid: &Grid<u32>) -> u32 {
+    let minimums: Vec<(usize, usize)> = find_minimums(grid);
+
+    let mut basin_lengths = minimums
+        .iter()
+        .map(|(x, y)| basin(grid, *x, *y).len())
+        .collect::<Vec<_>>();
+
+    basin_lengths.sort_unstable();
+
+    let res: usize = basin_lengths.iter().rev().take(3).product();
+
+    res as u32
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Grid<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const DAY: u8 = 9;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_digits(input).map_err(|invalid| ParseError::locate(Self::DAY, input, invalid))
+    }
+
+    fn part1(&self, input: &Self::Input) -> u32 {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> u32 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../inputs/examples/day9.txt");
+
+    #[test]
+    fn part1_example() {
+        let input = Day9.parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day9.part1(&input), 15);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day9.parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day9.part2(&input), 1134);
+    }
+}
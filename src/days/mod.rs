@@ -0,0 +1,39 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use crate::Puzzle;
+
+/// Last day with a solution.
+pub const LAST_DAY: u8 = 11;
+
+pub fn get(day: u8) -> Option<Box<dyn Puzzle>> {
+    let puzzle: Box<dyn Puzzle> = match day {
+        1 => Box::new(day1::Day1::default()),
+        2 => Box::new(day2::Day2),
+        3 => Box::new(day3::Day3::default()),
+        4 => Box::new(day4::Day4::default()),
+        5 => Box::new(day5::Day5::default()),
+        6 => Box::new(day6::Day6::default()),
+        7 => Box::new(day7::Day7),
+        8 => Box::new(day8::Day8),
+        9 => Box::new(day9::Day9),
+        10 => Box::new(day10::Day10),
+        11 => Box::new(day11::Day11::default()),
+        _ => return None,
+    };
+
+    Some(puzzle)
+}
+
+pub fn all() -> Vec<Box<dyn Puzzle>> {
+    (1..=LAST_DAY).filter_map(get).collect()
+}
@@ -0,0 +1,148 @@
+use std::{error::Error, fmt};
+
+/// What was wrong with a piece of puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input (or a section of it) has nothing in it.
+    Empty,
+    InvalidNumber,
+    UnknownInstruction,
+    UnexpectedChar,
+    /// Something specific was expected here, e.g. a separator.
+    Expected(&'static str),
+    /// A row or list has the wrong number of items.
+    WrongLength {
+        expected: usize,
+        found: usize,
+    },
+    /// Something that should only be there once is repeated.
+    Duplicate,
+    /// There's more of something than allowed, e.g. a course's instructions.
+    TooMany {
+        max: usize,
+        what: &'static str,
+    },
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseErrorKind::Empty => write!(f, "nothing to parse"),
+            ParseErrorKind::InvalidNumber => write!(f, "invalid number"),
+            ParseErrorKind::UnknownInstruction => write!(f, "unknown instruction"),
+            ParseErrorKind::UnexpectedChar => write!(f, "unexpected character"),
+            ParseErrorKind::Expected(what) => write!(f, "expected {}", what),
+            ParseErrorKind::WrongLength { expected, found } => {
+                write!(f, "expected {} items, found {}", expected, found)
+            }
+            ParseErrorKind::Duplicate => write!(f, "repeated value"),
+            ParseErrorKind::TooMany { max, what } => write!(f, "more than {} {}", max, what),
+        }
+    }
+}
+
+/// A parse failure pointing at the offending slice of the input.
+///
+/// Parsers return these instead of `ParseError` so that they don't need to keep track of
+/// line numbers: as long as `text` borrows from the puzzle input, `ParseError::locate` can
+/// work out where it came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Invalid<'a> {
+    pub text: &'a str,
+    pub kind: ParseErrorKind,
+}
+
+impl<'a> Invalid<'a> {
+    pub fn new(text: &'a str, kind: ParseErrorKind) -> Self {
+        Self { text, kind }
+    }
+}
+
+/// Parses a number, pointing at it if it's not valid.
+pub fn parse_number<T: std::str::FromStr>(text: &str) -> Result<T, Invalid<'_>> {
+    text.parse()
+        .map_err(|_| Invalid::new(text, ParseErrorKind::InvalidNumber))
+}
+
+/// A puzzle input that couldn't be parsed, with the location of the problem.
+///
+/// Lines and columns start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub kind: ParseErrorKind,
+}
+
+impl ParseError {
+    /// Finds the line and column of `invalid.text` inside `input`.
+    ///
+    /// Text that doesn't borrow from `input` is reported at the start of the input.
+    pub fn locate(day: u8, input: &str, invalid: Invalid) -> Self {
+        let start = input.as_ptr() as usize;
+        let position = invalid.text.as_ptr() as usize;
+        let offset = if position >= start && position <= start + input.len() {
+            position - start
+        } else {
+            0
+        };
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+
+        Self {
+            day,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: invalid.text.to_owned(),
+            kind: invalid.kind,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Day {} input, line {}, column {}: {}",
+            self.day, self.line, self.column, self.kind
+        )?;
+
+        match self.kind {
+            _ if self.text.is_empty() => {}
+            ParseErrorKind::Expected(_) => write!(f, ", found {:?}", self.text)?,
+            _ => write!(f, " {:?}", self.text)?,
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_points_at_borrowed_text() {
+        let input = "forward 5\ndown x\nup 3";
+        let text = &input[15..16];
+
+        let error = ParseError::locate(2, input, Invalid::new(text, ParseErrorKind::InvalidNumber));
+
+        assert_eq!((error.line, error.column), (2, 6));
+        assert_eq!(error.text, "x");
+    }
+
+    #[test]
+    fn locate_falls_back_to_start_for_foreign_text() {
+        let input = "1\n2\n3";
+
+        let error = ParseError::locate(1, input, Invalid::new("x", ParseErrorKind::InvalidNumber));
+
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+}
@@ -0,0 +1,406 @@
+//! Random inputs for every day, shaped like the real ones.
+//!
+//! Generated inputs respect whatever the solutions assume about their input (e.g. no ties
+//! in day 3, every bingo board eventually winning), so both parts can always be solved.
+
+use std::{
+    fmt::{Display, Write},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::grid::Grid;
+
+/// A small, seedable pseudo random number generator (SplitMix64).
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Seeds the generator from the system clock.
+    pub fn from_time() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_nanos() as u64);
+
+        Self::new(nanos)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..bound`, which must not be empty.
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "Empty range");
+
+        ((u128::from(self.next_u64()) * bound as u128) >> 64) as usize
+    }
+
+    /// A number in `low..=high`.
+    pub fn between(&mut self, low: usize, high: usize) -> usize {
+        low + self.below(high - low + 1)
+    }
+
+    pub fn coin(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// How big `generate` makes each day's input by default, roughly matching the real inputs.
+pub fn default_size(day: u8) -> Option<usize> {
+    let size = match day {
+        1 => 2000,
+        2 => 1000,
+        3 => 1000,
+        4 => 100,
+        5 => 500,
+        6 => 300,
+        7 => 1000,
+        8 => 200,
+        9 => 100,
+        10 => 100,
+        11 => 10,
+        _ => return None,
+    };
+
+    Some(size)
+}
+
+/// Generates an input for `day`, with the default knobs of that day.
+///
+/// `size` is the number of lines for most days, but the number of boards for day 4, and
+/// the side of the grid for days 9 and 11.
+pub fn generate(day: u8, size: usize, rng: &mut Rng) -> Option<String> {
+    let input = match day {
+        1 => sonar_depths(rng, size),
+        2 => course(rng, size),
+        3 => diagnostic_report(rng, size, 12),
+        4 => bingo(rng, size, 5),
+        5 => vent_lines(rng, size, 1000, true),
+        6 => fish_timers(rng, size),
+        7 => crab_positions(rng, size, 2000),
+        8 => seven_segment_entries(rng, size),
+        9 => digit_grid(rng, size, size),
+        10 => navigation_lines(rng, size, 100),
+        11 => octopus_grid(rng, size, size),
+        _ => return None,
+    };
+
+    Some(input)
+}
+
+/// Depths that wander up and down, but mostly down, like a sonar sweep.
+pub fn sonar_depths(rng: &mut Rng, count: usize) -> String {
+    let mut depth = rng.between(100, 200);
+
+    join_lines((0..count).map(|_| {
+        depth = (depth + rng.between(0, 30)).saturating_sub(10);
+
+        depth.to_string()
+    }))
+}
+
+/// Submarine commands that never take it (or its aim) above the surface.
+pub fn course(rng: &mut Rng, count: usize) -> String {
+    let mut depth = 0;
+
+    join_lines((0..count).map(|_| {
+        let value = rng.between(1, 9);
+
+        match rng.below(3) {
+            0 => format!("forward {}", value),
+            1 if depth > 0 => {
+                let value = value.min(depth);
+                depth -= value;
+
+                format!("up {}", value)
+            }
+            _ => {
+                depth += value;
+
+                format!("down {}", value)
+            }
+        }
+    }))
+}
+
+/// Binary numbers `bits` wide.
+///
+/// An even `count` gets one more number, so that no position has as many ones as zeroes.
+pub fn diagnostic_report(rng: &mut Rng, count: usize, bits: usize) -> String {
+    join_lines((0..count | 1).map(|_| {
+        (0..bits)
+            .map(|_| if rng.coin() { '1' } else { '0' })
+            .collect::<String>()
+    }))
+}
+
+/// Draws followed by `boards` boards of `size` by `size` numbers.
+///
+/// Numbers don't repeat within a board, and every number is eventually drawn.
+pub fn bingo(rng: &mut Rng, boards: usize, size: usize) -> String {
+    let cells = size * size;
+    let mut numbers = (0..(cells * 2).max(100)).collect::<Vec<_>>();
+    let width = (numbers.len() - 1).to_string().len();
+
+    rng.shuffle(&mut numbers);
+    let mut result = join(numbers.iter(), ",");
+
+    for _ in 0..boards {
+        rng.shuffle(&mut numbers);
+        result.push('\n');
+
+        for row in numbers[..cells].chunks(size) {
+            result.push('\n');
+            let row = row.iter().map(|n| format!("{:>1$}", n, width));
+            result.push_str(&join(row, " "));
+        }
+    }
+
+    result
+}
+
+/// Horizontal, vertical and (optionally) diagonal lines inside an `extent` by `extent` area.
+pub fn vent_lines(rng: &mut Rng, count: usize, extent: usize, diagonals: bool) -> String {
+    assert!(extent > 1, "Lines need at least 2 positions to fit");
+
+    let kinds = if diagonals { 3 } else { 2 };
+
+    join_lines((0..count).map(|_| {
+        let (x1, y1) = (rng.below(extent), rng.below(extent));
+        // Any other position on the same row or column.
+        let other = |rng: &mut Rng, start| (start + rng.between(1, extent - 1)) % extent;
+
+        let (x2, y2) = match rng.below(kinds) {
+            0 => (other(rng, x1), y1),
+            1 => (x1, other(rng, y1)),
+            _ => {
+                // Going towards the furthest corner always leaves room for the line.
+                let dx = if x1 < extent / 2 { extent - 1 - x1 } else { x1 };
+                let dy = if y1 < extent / 2 { extent - 1 - y1 } else { y1 };
+                let length = rng.between(1, dx.min(dy));
+                let step = |start: usize| {
+                    if start < extent / 2 {
+                        start + length
+                    } else {
+                        start - length
+                    }
+                };
+
+                (step(x1), step(y1))
+            }
+        };
+
+        format!("{},{} -> {},{}", x1, y1, x2, y2)
+    }))
+}
+
+pub fn fish_timers(rng: &mut Rng, count: usize) -> String {
+    join((0..count).map(|_| rng.below(9)), ",")
+}
+
+pub fn crab_positions(rng: &mut Rng, count: usize, max_position: usize) -> String {
+    join((0..count).map(|_| rng.between(0, max_position)), ",")
+}
+
+/// Segments lit by each digit on a correctly wired display.
+const SEGMENTS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+/// Displays with their wires scrambled, each showing every digit once and then 4 more.
+pub fn seven_segment_entries(rng: &mut Rng, count: usize) -> String {
+    join_lines((0..count).map(|_| {
+        let mut wires = *b"abcdefg";
+        rng.shuffle(&mut wires);
+
+        let scramble = |rng: &mut Rng, digit: usize| {
+            let mut signal = SEGMENTS[digit]
+                .bytes()
+                .map(|segment| char::from(wires[usize::from(segment - b'a')]))
+                .collect::<Vec<_>>();
+            rng.shuffle(&mut signal);
+
+            signal.into_iter().collect::<String>()
+        };
+
+        let mut patterns = (0..10)
+            .map(|digit| scramble(rng, digit))
+            .collect::<Vec<_>>();
+        let output = (0..4)
+            .map(|_| {
+                let digit = rng.below(10);
+                scramble(rng, digit)
+            })
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut patterns);
+
+        format!("{} | {}", patterns.join(" "), output.join(" "))
+    }))
+}
+
+/// A grid of random digits, like a heightmap.
+pub fn digit_grid(rng: &mut Rng, width: usize, height: usize) -> String {
+    join_lines((0..height).map(|_| {
+        (0..width)
+            .map(|_| char::from(b'0' + rng.below(10) as u8))
+            .collect::<String>()
+    }))
+}
+
+/// Octopus energy levels that all flash at once after a few steps.
+///
+/// Octopuses get a random order to flash in on the step that synchronizes them, and each one
+/// starts high enough to flash once its neighbors earlier in that order have. Lowering every
+/// level by the same amount only delays that step, so grids of any size synchronize.
+pub fn octopus_grid(rng: &mut Rng, width: usize, height: usize) -> String {
+    let mut order = (0..width * height).collect::<Vec<_>>();
+    rng.shuffle(&mut order);
+    let order = Grid::from_vec(width, height, order).expect("One position per octopus");
+
+    let levels = Grid::from_fn(width, height, |x, y| {
+        let earlier = order
+            .neighbors8(x, y)
+            .filter(|&(nx, ny)| order[(nx, ny)] < order[(x, y)])
+            .count();
+
+        rng.between(9 - earlier.min(9), 9)
+    });
+    let delay = rng.below(levels.iter().copied().min().unwrap_or(0) + 1);
+
+    join_lines(levels.rows().map(|row| {
+        row.iter()
+            .map(|level| char::from(b'0' + (level - delay) as u8))
+            .collect::<String>()
+    }))
+}
+
+const OPENING: [char; 4] = ['(', '[', '{', '<'];
+const CLOSING: [char; 4] = [')', ']', '}', '>'];
+const MAX_NESTING: usize = 20;
+
+/// Chunk lines of up to `length` characters, some corrupted and the rest incomplete.
+///
+/// There's always an odd number of incomplete lines, so part 2 has a middle score. Chunks
+/// nest at most `MAX_NESTING` deep, which keeps completion scores within an `i64`.
+pub fn navigation_lines(rng: &mut Rng, count: usize, length: usize) -> String {
+    let incomplete = ((count / 2) | 1).min(count);
+    let mut corrupted = (0..count).map(|i| i >= incomplete).collect::<Vec<_>>();
+    rng.shuffle(&mut corrupted);
+
+    join_lines(corrupted.into_iter().map(|corrupted| {
+        let mut line = String::new();
+        let mut open = vec![];
+        let corrupt_at = rng.below(length.max(1));
+
+        for i in 0..length {
+            if corrupted && i >= corrupt_at && !open.is_empty() {
+                break;
+            }
+
+            if open.len() == MAX_NESTING || !open.is_empty() && rng.coin() {
+                line.push(CLOSING[open.pop().unwrap()]);
+            } else {
+                let kind = rng.below(4);
+                open.push(kind);
+                line.push(OPENING[kind]);
+            }
+        }
+
+        // Both kinds of line need a chunk left open, incomplete ones to complete it and
+        // corrupted ones to close it with the wrong character.
+        if open.is_empty() {
+            let kind = rng.below(4);
+            open.push(kind);
+            line.push(OPENING[kind]);
+        }
+        if corrupted {
+            let wrong = (open.last().unwrap() + rng.between(1, 3)) % 4;
+            line.push(CLOSING[wrong]);
+        }
+
+        line
+    }))
+}
+
+fn join<T: Display>(items: impl Iterator<Item = T>, separator: &str) -> String {
+    let mut result = String::new();
+
+    for (i, item) in items.enumerate() {
+        if i > 0 {
+            result.push_str(separator);
+        }
+        write!(result, "{}", item).unwrap();
+    }
+
+    result
+}
+
+fn join_lines(lines: impl Iterator<Item = String>) -> String {
+    join(lines, "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        days::{
+            self,
+            day11::{self, Octopuses},
+        },
+        Part,
+    };
+
+    #[test]
+    fn same_seed_same_input() {
+        let first = generate(8, 5, &mut Rng::new(7));
+
+        assert_eq!(first, generate(8, 5, &mut Rng::new(7)));
+        assert_ne!(first, generate(8, 5, &mut Rng::new(8)));
+    }
+
+    #[test]
+    fn generated_inputs_can_be_solved() {
+        for (seed, size) in (0..20)
+            .map(|seed| (seed, 9))
+            .chain(vec![(20, 40), (21, 100)])
+        {
+            let mut rng = Rng::new(seed);
+
+            for puzzle in days::all() {
+                let input = generate(puzzle.day(), size, &mut rng).unwrap();
+
+                if let Err(error) = puzzle.solve(&input, &Part::ALL) {
+                    panic!("seed {}, size {}: {}\n{}", seed, size, error, input);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn octopus_grids_synchronize() {
+        for seed in 0..10 {
+            let grid = octopus_grid(&mut Rng::new(seed), 60, 40);
+            let octopuses = Octopuses::parse(&grid, 9).unwrap();
+
+            assert!(day11::synchronized_step(&octopuses, 10).is_some());
+        }
+    }
+}
@@ -0,0 +1,337 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::error::{Invalid, ParseErrorKind};
+
+/// Offsets of the 4 orthogonal neighbors.
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// Offsets of the 8 neighbors, orthogonal ones first.
+const ADJACENT: [(isize, isize); 8] = [
+    (-1, 0),
+    (1, 0),
+    (0, -1),
+    (0, 1),
+    (-1, -1),
+    (1, -1),
+    (-1, 1),
+    (1, 1),
+];
+
+/// A rectangular grid stored row by row in a single `Vec`.
+///
+/// Positions are `(x, y)` pairs, with `(0, 0)` at the top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells in row-major order, if there's exactly `width * height` of them.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Option<Self> {
+        if cells.len() != width * height {
+            return None;
+        }
+
+        Some(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Parses one row per line, turning each character into a cell with `f`.
+    ///
+    /// Characters rejected by `f` are reported as not being `expected`.
+    pub fn parse<'a, F>(
+        input: &'a str,
+        expected: &'static str,
+        mut f: F,
+    ) -> Result<Self, Invalid<'a>>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+
+        if width == 0 {
+            return Err(Invalid::new(input, ParseErrorKind::Empty));
+        }
+
+        let mut cells = vec![];
+        let mut height = 0;
+        for line in input.lines() {
+            let mut row_width = 0;
+            for (index, c) in line.char_indices() {
+                let cell = f(c).ok_or_else(|| {
+                    let text = &line[index..index + c.len_utf8()];
+
+                    Invalid::new(text, ParseErrorKind::Expected(expected))
+                })?;
+
+                cells.push(cell);
+                row_width += 1;
+            }
+
+            if row_width != width {
+                return Err(Invalid::new(
+                    line,
+                    ParseErrorKind::WrongLength {
+                        expected: width,
+                        found: row_width,
+                    },
+                ));
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if self.contains(x, y) {
+            Some(&self.cells[x + y * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            Some(&mut self.cells[x + y * self.width])
+        } else {
+            None
+        }
+    }
+
+    /// Moves from `(x, y)` by `(dx, dy)`, if that stays inside the grid.
+    pub fn offset(&self, x: usize, y: usize, dx: isize, dy: isize) -> Option<(usize, usize)> {
+        offset(self.width, self.height, (x, y), (dx, dy))
+    }
+
+    /// In-bounds positions up, down, left and right of `(x, y)`.
+    ///
+    /// The iterator doesn't borrow the grid, so it can be modified while visiting them.
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |delta| offset(width, height, (x, y), *delta))
+    }
+
+    /// Like `neighbors4`, but also including the diagonals.
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+
+        ADJACENT
+            .iter()
+            .filter_map(move |delta| offset(width, height, (x, y), *delta))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} out of bounds", x);
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Cells inside the rectangle at `(x, y)` of the given size, clipped to the grid.
+    pub fn region(
+        &self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let x_end = x.saturating_add(width).min(self.width);
+        let y_end = y.saturating_add(height).min(self.height);
+
+        (y..y_end)
+            .flat_map(move |y| (x..x_end).map(move |x| (x, y)))
+            .map(move |(x, y)| ((x, y), &self[(x, y)]))
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl Grid<u32> {
+    pub fn parse_digits(input: &str) -> Result<Self, Invalid<'_>> {
+        Self::parse(input, "a digit", |c| c.to_digit(10))
+    }
+}
+
+impl Grid<char> {
+    pub fn parse_chars(input: &str) -> Result<Self, Invalid<'_>> {
+        Self::parse(input, "a character", Some)
+    }
+}
+
+fn offset(
+    width: usize,
+    height: usize,
+    (x, y): (usize, usize),
+    (dx, dy): (isize, isize),
+) -> Option<(usize, usize)> {
+    let x = x.checked_add_signed(dx).filter(|x| *x < width)?;
+    let y = y.checked_add_signed(dy).filter(|y| *y < height)?;
+
+    Some((x, y))
+}
+
+/// Unchecked access, panics when the position is outside the grid.
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(self.contains(x, y), "Position {:?} out of bounds", (x, y));
+
+        &self.cells[x + y * self.width]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(self.contains(x, y), "Position {:?} out of bounds", (x, y));
+
+        &mut self.cells[x + y * self.width]
+    }
+}
+
+/// One line per row, with the cells written next to each other.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbors_stay_in_bounds() {
+        let grid = Grid::new(3, 2, 0);
+
+        assert_eq!(grid.neighbors4(0, 0).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors8(2, 1).count(), 3);
+        assert_eq!(grid.neighbors8(1, 0).count(), 5);
+    }
+
+    #[test]
+    fn rows_columns_and_regions() {
+        let grid = Grid::parse_digits("123\n456\n789").unwrap();
+
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [3, 6, 9]);
+        assert_eq!(
+            grid.region(1, 1, 5, 5).map(|(_, n)| *n).collect::<Vec<_>>(),
+            [5, 6, 8, 9]
+        );
+        assert_eq!(grid.to_string(), "123\n456\n789");
+    }
+
+    #[test]
+    fn parse_rejects_ragged_rows() {
+        let input = "123\n45";
+
+        let error = Grid::parse_digits(input).unwrap_err();
+
+        assert_eq!(error.text, "45");
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::WrongLength {
+                expected: 3,
+                found: 2
+            }
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use std::{
+    env, fmt, fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+use crate::{days, Puzzle};
+
+/// Directory searched for `day<N>.txt` when no input is given explicitly.
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+
+/// Environment variable that overrides `DEFAULT_INPUT_DIR`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// `day<N>.txt` inside the inputs directory.
+    Default,
+    Stdin,
+    Path(PathBuf),
+}
+
+impl Source {
+    /// Interprets a `--input` argument, where `-` stands for stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::Path(PathBuf::from(arg))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub day: u8,
+    pub path: Option<PathBuf>,
+    pub error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.path {
+            Some(path) if self.error.kind() == io::ErrorKind::NotFound => write!(
+                f,
+                "No input for day {} at {} (use --input <path>, or - to read stdin)",
+                self.day,
+                path.display()
+            ),
+            Some(path) => write!(
+                f,
+                "Could not read input for day {} from {}: {}",
+                self.day,
+                path.display(),
+                self.error
+            ),
+            None => write!(
+                f,
+                "Could not read input for day {} from stdin: {}",
+                self.day, self.error
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Path of a day's input inside the inputs directory.
+pub fn default_path(day: u8) -> PathBuf {
+    let dir = env::var_os(INPUT_DIR_VAR).unwrap_or_else(|| DEFAULT_INPUT_DIR.into());
+
+    PathBuf::from(dir).join(format!("day{}.txt", day))
+}
+
+/// Reads a day's puzzle input.
+///
+/// Trailing newlines are stripped, so inputs saved straight from the website parse the same
+/// as the ones in the repository.
+pub fn load(day: u8, source: &Source) -> Result<String, InputError> {
+    let (path, result) = match source {
+        Source::Stdin => {
+            let mut input = String::new();
+            let result = io::stdin().read_to_string(&mut input).map(|_| input);
+
+            (None, result)
+        }
+        Source::Default => {
+            let path = default_path(day);
+            let result = fs::read_to_string(&path);
+
+            (Some(path), result)
+        }
+        Source::Path(path) => (Some(path.clone()), fs::read_to_string(path)),
+    };
+
+    match result {
+        Ok(mut input) => {
+            let len = input.trim_end_matches(&['\n', '\r'][..]).len();
+            input.truncate(len);
+
+            Ok(input)
+        }
+        Err(error) => Err(InputError { day, path, error }),
+    }
+}
+
+/// Short, stable identifier of an input's contents (64 bit FNV-1a, in hex).
+///
+/// Unlike `DefaultHasher`, this is guaranteed not to change between Rust releases, so it
+/// can be stored in files.
+pub fn fingerprint(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+
+    format!("{:016x}", hash)
+}
+
+/// Identifies what a puzzle's answers depend on: the input's `fingerprint`, followed by every
+/// parameter that differs from the one the day starts with, like `2f1c0a9e4b7d3a61
+/// part1_days=18`.
+pub fn key(puzzle: &dyn Puzzle, input: &str) -> String {
+    let defaults = days::get(puzzle.day())
+        .map(|day| day.params())
+        .unwrap_or_default();
+
+    puzzle
+        .params()
+        .into_iter()
+        .filter(|param| !defaults.contains(param))
+        .fold(fingerprint(input), |key, param| {
+            format!("{} {}={}", key, param.name, param.value)
+        })
+}
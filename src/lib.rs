@@ -0,0 +1,15 @@
+pub mod answers;
+pub mod bench;
+pub mod config;
+pub mod days;
+pub mod error;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod output;
+pub mod runner;
+pub mod solution;
+
+pub use error::ParseError;
+pub use solution::{Part, Puzzle, Solution};
@@ -0,0 +1,174 @@
+use std::{fmt::Write, time::Duration};
+
+use crate::{input, json::Value, ParseError, Part, Puzzle};
+
+/// How the runner prints its results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The usual `Part 1:` / answer listing, meant for people.
+    Text,
+    /// An array with one object per answer.
+    Json,
+    /// A header row and one row per answer.
+    Csv,
+}
+
+impl Format {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            "csv" => Some(Format::Csv),
+            _ => None,
+        }
+    }
+
+    /// Renders the records, with `Day <n>:` headers in text mode if `day_headers` is set.
+    pub fn render(self, records: &[Record], day_headers: bool) -> String {
+        match self {
+            Format::Text => text(records, day_headers),
+            Format::Json => json(records).pretty(),
+            Format::Csv => csv(records),
+        }
+    }
+}
+
+/// One answer along with how it was obtained.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    /// Time spent parsing the input, shared by both parts of a day.
+    pub parse_time: Duration,
+    pub time: Duration,
+    /// `input::key` of the input and parameters.
+    pub input: String,
+}
+
+/// Solves the requested parts of a day, keeping track of timings.
+pub fn records(
+    puzzle: &dyn Puzzle,
+    input: &str,
+    parts: &[Part],
+) -> Result<Vec<Record>, ParseError> {
+    let (answers, timings) = puzzle.solve_timed(input, parts)?;
+    let parse_time = timings.parse;
+    let fingerprint = input::key(puzzle, input);
+
+    let records = answers
+        .into_iter()
+        .zip(timings.parts)
+        .map(|((part, answer), (_, time))| Record {
+            day: puzzle.day(),
+            part,
+            answer,
+            parse_time,
+            time,
+            input: fingerprint.clone(),
+        })
+        .collect();
+
+    Ok(records)
+}
+
+pub fn text(records: &[Record], day_headers: bool) -> String {
+    let mut result = String::new();
+
+    for (i, record) in records.iter().enumerate() {
+        let new_day = i == 0 || records[i - 1].day != record.day;
+
+        if day_headers && new_day {
+            writeln!(result, "Day {}:", record.day).unwrap();
+        }
+        writeln!(result, "{}:", record.part).unwrap();
+        writeln!(result, "\t{}", record.answer).unwrap();
+    }
+
+    result.pop();
+    result
+}
+
+pub fn json(records: &[Record]) -> Value {
+    let records = records
+        .iter()
+        .map(|record| {
+            Value::Object(vec![
+                ("day".to_owned(), u64::from(record.day).into()),
+                ("part".to_owned(), u64::from(record.part.number()).into()),
+                ("answer".to_owned(), record.answer.as_str().into()),
+                (
+                    "parse_ns".to_owned(),
+                    (record.parse_time.as_nanos() as u64).into(),
+                ),
+                ("time_ns".to_owned(), (record.time.as_nanos() as u64).into()),
+                ("input".to_owned(), record.input.as_str().into()),
+            ])
+        })
+        .collect();
+
+    Value::Array(records)
+}
+
+pub fn csv(records: &[Record]) -> String {
+    let mut result = "day,part,answer,parse_ns,time_ns,input".to_owned();
+
+    for record in records {
+        write!(
+            result,
+            "\n{},{},{},{},{},{}",
+            record.day,
+            record.part.number(),
+            csv_field(&record.answer),
+            record.parse_time.as_nanos(),
+            record.time.as_nanos(),
+            record.input
+        )
+        .unwrap();
+    }
+
+    result
+}
+
+/// Quotes a field if it contains anything that would break the row apart.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(day: u8, part: Part, answer: &str) -> Record {
+        Record {
+            day,
+            part,
+            answer: answer.to_owned(),
+            parse_time: Duration::from_nanos(10),
+            time: Duration::from_nanos(20),
+            input: "0123456789abcdef".to_owned(),
+        }
+    }
+
+    #[test]
+    fn text_matches_the_classic_output() {
+        let records = [record(1, Part::One, "7"), record(1, Part::Two, "5")];
+
+        assert_eq!(text(&records, false), "Part 1:\n\t7\nPart 2:\n\t5");
+        assert_eq!(text(&records, true), "Day 1:\nPart 1:\n\t7\nPart 2:\n\t5");
+    }
+
+    #[test]
+    fn csv_quotes_awkward_answers() {
+        let records = [record(2, Part::One, "a,\"b\"")];
+
+        assert_eq!(
+            csv(&records),
+            "day,part,answer,parse_ns,time_ns,input\n2,1,\"a,\"\"b\"\"\",10,20,0123456789abcdef"
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use std::{env, path::PathBuf, process};
+
+use crate::{
+    bench,
+    config::{self, Config},
+    input::{self, Source},
+    output::{self, Format},
+    ParseError, Part, Puzzle,
+};
+
+/// Solves the requested parts of a day and prints the answers in the given format.
+pub fn print_answers(
+    puzzle: &dyn Puzzle,
+    input: &str,
+    parts: &[Part],
+    format: Format,
+) -> Result<(), ParseError> {
+    let records = output::records(puzzle, input, parts)?;
+    println!("{}", format.render(&records, false));
+
+    Ok(())
+}
+
+/// Prints the day's report, returning whether it has one.
+pub fn print_report(puzzle: &dyn Puzzle, input: &str) -> Result<bool, ParseError> {
+    let report = puzzle.report(input)?;
+
+    if let Some(report) = &report {
+        print!("{}", report);
+    }
+
+    Ok(report.is_some())
+}
+
+/// Formats a parse error together with the line it points at, e.g.:
+///
+/// ```text
+/// Day 2 input, line 3, column 9: invalid number "x"
+///   |
+/// 3 | forward x
+///   |         ^
+/// ```
+pub fn render_error(error: &ParseError, input: &str) -> String {
+    let line = match input.lines().nth(error.line - 1) {
+        Some(line) => line,
+        None => return error.to_string(),
+    };
+
+    let gutter = " ".repeat(error.line.to_string().len());
+    let indent = " ".repeat(error.column - 1);
+    let available = line.chars().count().saturating_sub(error.column - 1);
+    let underline = "^".repeat(error.text.chars().count().min(available).max(1));
+
+    format!(
+        "{}\n{} |\n{} | {}\n{} | {}{}",
+        error, gutter, error.line, line, gutter, indent, underline
+    )
+}
+
+/// Reads the config file and adds the command line settings on top, so they take precedence.
+///
+/// Settings are `name=value` assignments, each for the given day.
+pub fn load_config(path: Option<PathBuf>, settings: &[(u8, String)]) -> Config {
+    let path = path.unwrap_or_else(|| PathBuf::from(config::DEFAULT_PATH));
+
+    let mut config = Config::load(&path).unwrap_or_else(|error| {
+        eprintln!("Could not load config {}: {}", path.display(), error);
+        process::exit(1);
+    });
+
+    for (day, setting) in settings {
+        if config.set_arg(*day, setting).is_none() {
+            eprintln!("Expected `<name>=<value>` after --set, found {:?}", setting);
+            process::exit(2);
+        }
+    }
+
+    config
+}
+
+/// Applies the config to a puzzle, exiting if a setting doesn't fit it.
+pub fn configure(puzzle: &mut dyn Puzzle, config: &Config) {
+    if let Err(error) = config.apply(puzzle) {
+        eprintln!("Invalid config: {}", error);
+        process::exit(2);
+    }
+}
+
+/// Entry point of the single day binaries.
+///
+/// Accepts `--input <path>` (`-` for stdin), otherwise reads the day's file from the inputs
+/// directory. Answers are printed as text unless `--format json|csv` is given. With
+/// `--bench` it times each phase instead of just printing the answers, and with `--report`
+/// it prints the day's report instead. Puzzle parameters come from `aoc.toml` (or
+/// `--config <path>`), and `--set <name>=<value>` overrides them.
+pub fn day_main(puzzle: &mut dyn Puzzle) {
+    let mut source = Source::Default;
+    let mut format = Format::Text;
+    let mut iterations = None;
+    let mut report = false;
+    let mut config_path = None;
+    let mut settings = vec![];
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => match args.next() {
+                Some(path) => source = Source::from_arg(&path),
+                None => exit_with_usage("Missing value for --input"),
+            },
+            "--format" => match args.next().as_deref().and_then(Format::from_name) {
+                Some(value) => format = value,
+                None => exit_with_usage("Expected text, json or csv after --format"),
+            },
+            "--bench" => iterations = iterations.or(Some(bench::DEFAULT_ITERATIONS)),
+            "--report" => report = true,
+            "--iterations" => match args.next().and_then(|value| value.parse().ok()) {
+                Some(value) if value > 0 => iterations = Some(value),
+                _ => exit_with_usage("Expected a positive number after --iterations"),
+            },
+            "--config" => match args.next() {
+                Some(path) => config_path = Some(PathBuf::from(path)),
+                None => exit_with_usage("Missing value for --config"),
+            },
+            "--set" => match args.next() {
+                Some(setting) => settings.push((puzzle.day(), setting)),
+                None => exit_with_usage("Missing value for --set"),
+            },
+            _ => exit_with_usage(&format!("Unknown option: {}", arg)),
+        }
+    }
+
+    configure(puzzle, &load_config(config_path, &settings));
+    let puzzle = &*puzzle;
+
+    let input = input::load(puzzle.day(), &source).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(1);
+    });
+
+    let result = match iterations {
+        _ if report => print_report(puzzle, &input).map(|printed| {
+            if !printed {
+                eprintln!("Day {} has no report", puzzle.day());
+                process::exit(1);
+            }
+        }),
+        Some(iterations) => bench::run(puzzle, &input, iterations).map(|measurements| {
+            println!("{}", bench::table(&bench::compare(&measurements, &[], 0.0)));
+        }),
+        None => print_answers(puzzle, &input, &Part::ALL, format),
+    };
+
+    if let Err(error) = result {
+        eprintln!("{}", render_error(&error, &input));
+        process::exit(1);
+    }
+}
+
+fn exit_with_usage(error: &str) -> ! {
+    let name = env::args().next().unwrap_or_default();
+
+    eprintln!(
+        "{}\n\nUsage:\n    {} [--input <path>] [--format text|json|csv] [--bench [--iterations <n>]] [--report]\n        [--config <aoc.toml>] [--set <name>=<value>]...",
+        error, name
+    );
+    process::exit(2);
+}
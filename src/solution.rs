@@ -0,0 +1,162 @@
+use std::{
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    config::{Param, ParamError},
+    ParseError,
+};
+
+/// One of the two questions asked by every puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Part {}", self.number())
+    }
+}
+
+/// A day's puzzle: how to read its input and how to answer both parts.
+pub trait Solution {
+    /// The parsed puzzle input, shared by both parts.
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    const DAY: u8;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(&self, input: &Self::Input) -> Self::Answer1;
+    fn part2(&self, input: &Self::Input) -> Self::Answer2;
+
+    /// The puzzle's tweakable constants, with their current values.
+    fn params(&self) -> Vec<Param> {
+        vec![]
+    }
+
+    /// Changes one of the constants listed by `params`.
+    fn set_param(&mut self, name: &str, _value: &str) -> Result<(), ParamError> {
+        Err(ParamError::unknown(name))
+    }
+
+    /// A printable account of how the answers come about, for days that have one.
+    ///
+    /// `text` is the input that was parsed, for reports that point at parts of it.
+    fn report(&self, _text: &str, _input: &Self::Input) -> Option<String> {
+        None
+    }
+}
+
+/// How long each phase of a single run took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub parts: Vec<(Part, Duration)>,
+}
+
+/// Object safe view of a `Solution`, so that different days can be stored together.
+///
+/// Answers are rendered to strings since every day uses its own answer types.
+pub trait Puzzle {
+    fn day(&self) -> u8;
+    fn params(&self) -> Vec<Param>;
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError>;
+
+    /// Parses the input and builds the day's report, if it has one.
+    fn report(&self, input: &str) -> Result<Option<String>, ParseError>;
+
+    /// Like `solve`, but also measures how long parsing and each part took.
+    fn solve_timed(
+        &self,
+        input: &str,
+        parts: &[Part],
+    ) -> Result<(Vec<(Part, String)>, Timings), ParseError>;
+
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<(Part, String)>, ParseError> {
+        self.solve_timed(input, parts).map(|(answers, _)| answers)
+    }
+}
+
+impl<S: Solution> Puzzle for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn params(&self) -> Vec<Param> {
+        Solution::params(self)
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        Solution::set_param(self, name, value)
+    }
+
+    fn report(&self, input: &str) -> Result<Option<String>, ParseError> {
+        let parsed = self.parse(input)?;
+
+        Ok(Solution::report(self, input, &parsed))
+    }
+
+    fn solve_timed(
+        &self,
+        input: &str,
+        parts: &[Part],
+    ) -> Result<(Vec<(Part, String)>, Timings), ParseError> {
+        let start = Instant::now();
+        let input = self.parse(input)?;
+        let parse = start.elapsed();
+
+        let mut answers = Vec::with_capacity(parts.len());
+        let mut part_timings = Vec::with_capacity(parts.len());
+
+        for part in parts {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => {
+                    let answer = self.part1(&input);
+                    part_timings.push((*part, start.elapsed()));
+
+                    answer.to_string()
+                }
+                Part::Two => {
+                    let answer = self.part2(&input);
+                    part_timings.push((*part, start.elapsed()));
+
+                    answer.to_string()
+                }
+            };
+
+            answers.push((*part, answer));
+        }
+
+        let timings = Timings {
+            parse,
+            parts: part_timings,
+        };
+
+        Ok((answers, timings))
+    }
+}
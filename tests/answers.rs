@@ -0,0 +1,66 @@
+use std::path::Path;
+
+use aoc_2021::{
+    answers::{self, Answers, Status},
+    days,
+    input::{self, Source},
+    Part,
+};
+
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+#[test]
+fn real_inputs_match_accepted_answers() {
+    let answers = Answers::load(&root().join(answers::DEFAULT_PATH)).unwrap();
+
+    for puzzle in days::all() {
+        let source = Source::Path(root().join(format!("inputs/day{}.txt", puzzle.day())));
+        let input = input::load(puzzle.day(), &source).unwrap();
+
+        for check in answers::verify(&answers, puzzle.as_ref(), &input).unwrap() {
+            assert_eq!(
+                check.status,
+                Status::Pass,
+                "day {} part {} answered {}",
+                check.day,
+                check.part.number(),
+                check.actual
+            );
+        }
+    }
+}
+
+#[test]
+fn changed_params_have_their_own_answers() {
+    let answers = Answers::load(&root().join(answers::DEFAULT_PATH)).unwrap();
+    let mut puzzle = days::get(6).unwrap();
+    let input = input::load(6, &Source::Path(root().join("inputs/day6.txt"))).unwrap();
+
+    puzzle.set_param("part1_days", "18").unwrap();
+    let checks = answers::verify(&answers, puzzle.as_ref(), &input).unwrap();
+
+    for check in checks {
+        assert!(check.fingerprint.ends_with(" part1_days=18"));
+        assert_eq!(check.status, Status::Unknown);
+    }
+}
+
+#[test]
+fn every_day_has_accepted_answers() {
+    let answers = Answers::load(&root().join(answers::DEFAULT_PATH)).unwrap();
+
+    for puzzle in days::all() {
+        for part in &Part::ALL {
+            assert!(
+                answers
+                    .iter()
+                    .any(|answer| answer.day == puzzle.day() && answer.part == *part),
+                "day {} {}",
+                puzzle.day(),
+                part
+            );
+        }
+    }
+}